@@ -0,0 +1,307 @@
+use std::fmt::Debug;
+
+use ruint::aliases::U256;
+use thiserror::Error;
+
+use crate::full_mul_div::{from_sign_magnitude, sign_magnitude};
+use crate::rounding::round_div_uint;
+use crate::{Decimal, I256, RoundingMode, ScaledInteger, WideningMul};
+
+/// Error returned when reading a [`DecimalAccumulator`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum AccumulatorError {
+    #[error("Accumulated value does not fit the backing integer")]
+    Overflow,
+    #[error("Cannot take the mean of an empty accumulator")]
+    Empty,
+}
+
+/// Running total kept in the double-width partner of a backing integer (see
+/// [`WideningMul`]).
+pub trait WideAccumulate: WideningMul<Wide: Copy + Debug + Eq> {
+    const WIDE_ZERO: Self::Wide;
+
+    fn widen(self) -> Self::Wide;
+
+    fn checked_add_wide(lhs: Self::Wide, rhs: Self::Wide) -> Option<Self::Wide>;
+
+    /// Splits a wide value into its sign and magnitude.
+    fn wide_sign_magnitude(wide: Self::Wide) -> (bool, U256);
+}
+
+macro_rules! impl_primitive {
+    ($($primary:ty),+) => {
+        $(
+            impl WideAccumulate for $primary {
+                const WIDE_ZERO: Self::Wide = 0;
+
+                #[inline]
+                fn widen(self) -> Self::Wide {
+                    Self::Wide::from(self)
+                }
+
+                #[inline]
+                fn checked_add_wide(lhs: Self::Wide, rhs: Self::Wide) -> Option<Self::Wide> {
+                    lhs.checked_add(rhs)
+                }
+
+                #[inline]
+                fn wide_sign_magnitude(wide: Self::Wide) -> (bool, U256) {
+                    let (negative, magnitude) = sign_magnitude(wide);
+
+                    (negative, U256::from(magnitude))
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive!(u8, i8, u16, i16, u32, i32, u64, i64);
+
+impl WideAccumulate for u128 {
+    const WIDE_ZERO: U256 = U256::ZERO;
+
+    #[inline]
+    fn widen(self) -> U256 {
+        U256::from(self)
+    }
+
+    #[inline]
+    fn checked_add_wide(lhs: U256, rhs: U256) -> Option<U256> {
+        lhs.checked_add(rhs)
+    }
+
+    #[inline]
+    fn wide_sign_magnitude(wide: U256) -> (bool, U256) {
+        (false, wide)
+    }
+}
+
+impl WideAccumulate for i128 {
+    const WIDE_ZERO: I256 = I256::ZERO;
+
+    #[inline]
+    fn widen(self) -> I256 {
+        I256::from(self)
+    }
+
+    #[inline]
+    fn checked_add_wide(lhs: I256, rhs: I256) -> Option<I256> {
+        lhs.checked_add(rhs)
+    }
+
+    #[inline]
+    fn wide_sign_magnitude(wide: I256) -> (bool, U256) {
+        (wide.is_negative(), wide.unsigned_abs())
+    }
+}
+
+/// Sums many decimals without intermediate overflow.
+///
+/// The running total is kept in the double-width partner of `I` (e.g. `i128`
+/// for `i64` and [`I256`] for `i128`), so only the final [`total`] or
+/// [`mean`] needs to fit `I`. Should even the wide total overflow, the
+/// accumulator remembers it and every read reports
+/// [`AccumulatorError::Overflow`].
+///
+/// [`total`]: DecimalAccumulator::total
+/// [`mean`]: DecimalAccumulator::mean
+///
+/// # Examples
+///
+/// ```rust
+/// use const_decimal::{AccumulatorError, Decimal, DecimalAccumulator, RoundingMode};
+///
+/// let mut acc = DecimalAccumulator::<i64, 9>::new();
+/// acc.extend([Decimal::MAX, Decimal::MAX, Decimal::ZERO]);
+///
+/// assert_eq!(acc.count(), 3);
+/// assert_eq!(acc.total(), Err(AccumulatorError::Overflow));
+/// assert_eq!(acc.mean(RoundingMode::HalfEven), Ok(Decimal(6_148_914_691_236_517_205)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalAccumulator<I, const D: u8>
+where
+    I: WideAccumulate,
+{
+    total: I::Wide,
+    count: u64,
+    overflowed: bool,
+}
+
+impl<I, const D: u8> DecimalAccumulator<I, D>
+where
+    I: ScaledInteger<D> + WideAccumulate,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        DecimalAccumulator { total: I::WIDE_ZERO, count: 0, overflowed: false }
+    }
+
+    /// Adds `value` to the running total.
+    pub fn push(&mut self, value: Decimal<I, D>) {
+        self.add(value.0.widen(), 1);
+    }
+
+    /// Combines the totals of two accumulators, e.g. after a parallel
+    /// reduction.
+    pub fn merge(&mut self, other: Self) {
+        self.overflowed |= other.overflowed;
+        self.add(other.total, other.count);
+    }
+
+    /// The number of values accumulated.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the sum of every value, or an error if it does not fit `I`.
+    pub fn total(&self) -> Result<Decimal<I, D>, AccumulatorError> {
+        let (negative, magnitude) = self.sign_magnitude()?;
+
+        Self::narrow(negative, magnitude)
+    }
+
+    /// Returns the arithmetic mean of every value, rounded once according to
+    /// `mode`.
+    ///
+    /// Returns an error if no values were accumulated or the total overflowed
+    /// the wide integer.
+    pub fn mean(&self, mode: RoundingMode) -> Result<Decimal<I, D>, AccumulatorError> {
+        if self.count == 0 {
+            return Err(AccumulatorError::Empty);
+        }
+
+        let (negative, magnitude) = self.sign_magnitude()?;
+
+        Self::narrow(negative, round_div_uint(magnitude, U256::from(self.count), negative, mode))
+    }
+
+    fn add(&mut self, total: I::Wide, count: u64) {
+        match (I::checked_add_wide(self.total, total), self.count.checked_add(count)) {
+            (Some(total), Some(count)) => {
+                self.total = total;
+                self.count = count;
+            }
+            _ => self.overflowed = true,
+        }
+    }
+
+    fn sign_magnitude(&self) -> Result<(bool, U256), AccumulatorError> {
+        match self.overflowed {
+            true => Err(AccumulatorError::Overflow),
+            false => Ok(I::wide_sign_magnitude(self.total)),
+        }
+    }
+
+    fn narrow(negative: bool, magnitude: U256) -> Result<Decimal<I, D>, AccumulatorError> {
+        u128::try_from(magnitude)
+            .ok()
+            .and_then(|magnitude| from_sign_magnitude(negative, magnitude))
+            .map(Decimal)
+            .ok_or(AccumulatorError::Overflow)
+    }
+}
+
+impl<I, const D: u8> Default for DecimalAccumulator<I, D>
+where
+    I: ScaledInteger<D> + WideAccumulate,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, const D: u8> Extend<Decimal<I, D>> for DecimalAccumulator<I, D>
+where
+    I: ScaledInteger<D> + WideAccumulate,
+{
+    fn extend<It: IntoIterator<Item = Decimal<I, D>>>(&mut self, iter: It) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, I, const D: u8> Extend<&'a Decimal<I, D>> for DecimalAccumulator<I, D>
+where
+    I: ScaledInteger<D> + WideAccumulate,
+{
+    fn extend<It: IntoIterator<Item = &'a Decimal<I, D>>>(&mut self, iter: It) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::base::num::basic::traits::Zero;
+    use malachite::{Integer, Rational};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::macros::generate_tests_for_common_variants;
+    use crate::rounding::reference_round;
+
+    #[test]
+    fn empty() {
+        let acc = DecimalAccumulator::<u64, 9>::default();
+
+        assert_eq!(acc.count(), 0);
+        assert_eq!(acc.total(), Ok(Decimal::ZERO));
+        assert_eq!(acc.mean(RoundingMode::HalfEven), Err(AccumulatorError::Empty));
+    }
+
+    #[test]
+    fn wide_overflow_is_sticky() {
+        let mut acc = DecimalAccumulator::<u8, 1>::new();
+        acc.extend([Decimal::MAX; 258]);
+        assert_eq!(acc.total(), Err(AccumulatorError::Overflow));
+        assert_eq!(acc.mean(RoundingMode::HalfEven), Err(AccumulatorError::Overflow));
+
+        let mut other = DecimalAccumulator::<u8, 1>::new();
+        other.push(Decimal::ONE);
+        other.merge(acc);
+        assert_eq!(other.total(), Err(AccumulatorError::Overflow));
+    }
+
+    #[test]
+    fn i128_mean_beyond_max() {
+        let mut acc = DecimalAccumulator::<i128, 18>::new();
+        acc.extend(&[Decimal::MIN, Decimal::MIN, Decimal::MIN]);
+
+        assert_eq!(acc.total(), Err(AccumulatorError::Overflow));
+        assert_eq!(acc.mean(RoundingMode::Floor), Ok(Decimal::MIN));
+    }
+
+    fn differential_fuzz_accumulator<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + WideAccumulate + Arbitrary + Into<Integer> + 'static,
+    {
+        let values = proptest::collection::vec(any::<Decimal<I, D>>(), 0..32);
+        proptest!(|(values in values, split in 0usize..32, mode: RoundingMode)| {
+            let split = std::cmp::min(split, values.len());
+            let mut lhs = DecimalAccumulator::<I, D>::new();
+            let mut rhs = DecimalAccumulator::<I, D>::new();
+            lhs.extend(&values[..split]);
+            rhs.extend(values[split..].iter().copied());
+            lhs.merge(rhs);
+
+            let total = values.iter().fold(Integer::ZERO, |acc, value| acc + value.0.into());
+            let fits = |x: &Integer| I::min_value().into() <= *x && *x <= I::max_value().into();
+            assert_eq!(lhs.count(), values.len() as u64);
+            assert_eq!(lhs.total().ok().map(|out| out.0.into()), fits(&total).then_some(total.clone()));
+
+            match values.is_empty() {
+                true => assert_eq!(lhs.mean(mode), Err(AccumulatorError::Empty)),
+                false => {
+                    let exact = Rational::from_integers(total, Integer::from(values.len()));
+                    let reference = reference_round(&exact, mode);
+                    assert_eq!(lhs.mean(mode).map(|out| out.0.into()), Ok(reference));
+                }
+            }
+        });
+    }
+
+    generate_tests_for_common_variants!(differential_fuzz_accumulator);
+}
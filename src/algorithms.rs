@@ -1,5 +1,53 @@
-use crate::ScaledInteger;
+use ruint::aliases::U512;
 
+use crate::full_mul_div::{from_sign_magnitude, sign_magnitude};
+use crate::rounding::round_div_uint;
+use crate::{Decimal, DecimalError, Operation, RoundingMode, ScaledInteger};
+
+/// Number of fractional digits carried by [`Fixed`].
+const PRECISION: u8 = 64;
+
+/// `10^64`, the scaling factor of [`Fixed`].
+const SCALE: U512 = U512::from_limbs([
+    0x0,
+    0x6e38_ed64_bf6a_1f01,
+    0xe93f_f9f4_daa7_97ed,
+    0x0018_4f03,
+    0x0,
+    0x0,
+    0x0,
+    0x0,
+]);
+
+/// `ln(2) * 10^64`, rounded to nearest.
+const LN2: U512 = U512::from_limbs([
+    0x0ab6_1e00_79bf_e6df,
+    0x15f4_c452_11bf_4ade,
+    0x2aa4_9d40_09a6_0be2,
+    0x0010_d977,
+    0x0,
+    0x0,
+    0x0,
+    0x0,
+]);
+
+/// `ln(10) * 10^64`, rounded to nearest.
+const LN10: U512 = U512::from_limbs([
+    0xea71_dc16_17d1_2f22,
+    0xdbb8_f113_8566_b86c,
+    0xafb4_2c6b_2c86_25b2,
+    0x0037_f905,
+    0x0,
+    0x0,
+    0x0,
+    0x0,
+]);
+
+/// Beyond `2^130` no result fits a 128 bit backing; below `2^-130` every
+/// result rounds to zero.
+const MAX_BINARY_EXPONENT: u64 = 130;
+
+#[cfg(test)]
 pub(crate) fn log10<const D: u8, I: ScaledInteger<D>>(mut input: I) -> I {
     let mut result = I::ZERO;
     while input > I::ZERO {
@@ -9,3 +57,482 @@ pub(crate) fn log10<const D: u8, I: ScaledInteger<D>>(mut input: I) -> I {
 
     result
 }
+
+/// Signed fixed-point value with [`PRECISION`] fractional digits.
+///
+/// Every operation truncates at `10^-64`, which keeps the accumulated error
+/// many orders of magnitude below the last digit of any backing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Fixed {
+    negative: bool,
+    magnitude: U512,
+}
+
+impl Fixed {
+    const ZERO: Fixed = Fixed { negative: false, magnitude: U512::ZERO };
+
+    fn from_decimal<I, const D: u8>(value: I) -> Self
+    where
+        I: ScaledInteger<D>,
+    {
+        let (negative, magnitude) = sign_magnitude(value);
+
+        Fixed { negative, magnitude: U512::from(magnitude) * pow10(PRECISION - D) }
+    }
+
+    /// Rounds to the nearest `I` at scale `D` (ties to even), returning `None`
+    /// if the result does not fit.
+    fn to_decimal<I, const D: u8>(self) -> Option<I>
+    where
+        I: ScaledInteger<D>,
+    {
+        let numer = self.magnitude.checked_mul(pow10(D))?;
+        let magnitude = round_div_uint(numer, SCALE, self.negative, RoundingMode::HalfEven);
+
+        from_sign_magnitude(self.negative, u128::try_from(magnitude).ok()?)
+    }
+
+    /// Computes `lhs - rhs` on two non-negative magnitudes.
+    fn difference(lhs: U512, rhs: U512) -> Self {
+        match lhs >= rhs {
+            true => Fixed { negative: false, magnitude: lhs - rhs },
+            false => Fixed { negative: true, magnitude: rhs - lhs },
+        }
+    }
+
+    /// Computes `e^self`, returning `None` if the result exceeds
+    /// `2^MAX_BINARY_EXPONENT`.
+    fn exp(self) -> Option<Self> {
+        match (self.negative, exp_magnitude(self.magnitude)) {
+            (false, out) => out.map(|magnitude| Fixed { negative: false, magnitude }),
+            // SAFETY: `e^x >= 1` so the divisor is non-zero.
+            (true, Some(out)) => Some(Fixed { negative: false, magnitude: SCALE * SCALE / out }),
+            (true, None) => Some(Fixed::ZERO),
+        }
+    }
+
+    /// Computes `ln(value / 10^decimals)` for a positive `value`.
+    fn ln(value: u128, decimals: u8) -> Self {
+        debug_assert!(value > 0);
+
+        // Reduce to `value = m * 2^halvings * 10^exponent` with `m` in [1, 2).
+        let exponent = value.ilog10();
+        let mut m = U512::from(value) * pow10(PRECISION - exponent as u8);
+        let mut halvings = 0u64;
+        while m >= SCALE << 1 {
+            m >>= 1;
+            halvings += 1;
+        }
+
+        // ln(m) = 2 * atanh(z) = 2 * (z + z^3 / 3 + z^5 / 5 + ...), with
+        // `z = (m - 1) / (m + 1)` in [0, 1/3).
+        let z = (m - SCALE) * SCALE / (m + SCALE);
+        let z2 = z * z / SCALE;
+        let mut series = z;
+        let mut power = z;
+        let mut k = 3u64;
+        loop {
+            power = power * z2 / SCALE;
+            let term = power / U512::from(k);
+            if term.is_zero() {
+                break;
+            }
+
+            series += term;
+            k += 2;
+        }
+
+        let positive = (series << 1) + LN2 * U512::from(halvings);
+        let exponent = i64::from(exponent) - i64::from(decimals);
+        let powers_of_ten = LN10 * U512::from(exponent.unsigned_abs());
+        match exponent.is_negative() {
+            true => Fixed::difference(positive, powers_of_ten),
+            false => Fixed { negative: false, magnitude: positive + powers_of_ten },
+        }
+    }
+
+    /// Multiplies by `value / 10^decimals`.
+    fn mul_decimal<I, const D: u8>(self, value: I) -> Self
+    where
+        I: ScaledInteger<D>,
+    {
+        let (negative, magnitude) = sign_magnitude(value);
+
+        Fixed {
+            negative: self.negative != negative,
+            magnitude: self.magnitude * U512::from(magnitude) / pow10(D),
+        }
+    }
+
+    /// Divides by a positive constant.
+    fn div_constant(self, constant: U512) -> Self {
+        Fixed { negative: self.negative, magnitude: self.magnitude * SCALE / constant }
+    }
+}
+
+fn pow10(exp: u8) -> U512 {
+    U512::from(10).pow(U512::from(exp))
+}
+
+/// Computes `e^x` for a non-negative `x`, returning `None` if the result
+/// exceeds `2^MAX_BINARY_EXPONENT`.
+fn exp_magnitude(x: U512) -> Option<U512> {
+    // Reduce to `e^x = 2^n * e^r` with `r` in [0, ln(2)).
+    let (n, r) = x.div_rem(LN2);
+    if n > U512::from(MAX_BINARY_EXPONENT) {
+        return None;
+    }
+
+    // e^r = 1 + r + r^2 / 2! + r^3 / 3! + ...
+    let mut sum = SCALE;
+    let mut term = SCALE;
+    let mut k = 1u64;
+    while !term.is_zero() {
+        term = term * r / (SCALE * U512::from(k));
+        sum += term;
+        k += 1;
+    }
+
+    Some(sum << n.to::<usize>())
+}
+
+/// # Accuracy
+///
+/// The transcendental functions evaluate with 64 fractional digits internally
+/// and then round half to even, so every result is within 1 ULP (`10^-D`) of
+/// the exact value. They only use integer arithmetic and are therefore
+/// reproducible across platforms.
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    /// Computes `e^self`, within 1 ULP.
+    ///
+    /// Returns an error if the result does not fit the backing integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::Decimal;
+    ///
+    /// let one = Decimal::<i64, 9>::ONE;
+    /// assert_eq!(one.exp(), Ok("2.718281828".parse().unwrap()));
+    /// assert_eq!((-one).exp(), Ok("0.367879441".parse().unwrap()));
+    /// ```
+    pub fn exp(self) -> Result<Self, DecimalError<I>> {
+        Fixed::from_decimal(self.0)
+            .exp()
+            .and_then(Fixed::to_decimal)
+            .map(Decimal)
+            .ok_or(DecimalError::UnaryOverflow { op: Operation::Exp, value: self.0 })
+    }
+
+    /// Computes the natural logarithm, within 1 ULP.
+    ///
+    /// Returns an error if `self` is not positive or the result does not fit
+    /// the backing integer (e.g. a negative result for an unsigned backing).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::Decimal;
+    ///
+    /// let ten = "10".parse::<Decimal<i64, 9>>().unwrap();
+    /// assert_eq!(ten.ln(), Ok("2.302585093".parse().unwrap()));
+    /// ```
+    pub fn ln(self) -> Result<Self, DecimalError<I>> {
+        self.log(Operation::Ln, None)
+    }
+
+    /// Computes the base 10 logarithm, within 1 ULP (exact for powers of
+    /// ten).
+    ///
+    /// Returns an error if `self` is not positive or the result does not fit
+    /// the backing integer.
+    pub fn log10(self) -> Result<Self, DecimalError<I>> {
+        self.log(Operation::Log10, Some(LN10))
+    }
+
+    /// Computes the base 2 logarithm, within 1 ULP.
+    ///
+    /// Returns an error if `self` is not positive or the result does not fit
+    /// the backing integer.
+    pub fn log2(self) -> Result<Self, DecimalError<I>> {
+        self.log(Operation::Log2, Some(LN2))
+    }
+
+    /// Raises `self` to the decimal power `exp`, computed as
+    /// `e^(exp * ln(self))`, within 1 ULP.
+    ///
+    /// Returns an error if `self` is negative, if `self` is zero and `exp` is
+    /// negative, or if the result does not fit the backing integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::Decimal;
+    ///
+    /// let base = "2".parse::<Decimal<u64, 9>>().unwrap();
+    /// let half = "0.5".parse::<Decimal<u64, 9>>().unwrap();
+    ///
+    /// assert_eq!(base.powd(half), Ok("1.414213562".parse().unwrap()));
+    /// assert_eq!(base.powd(Decimal::TWO), Ok("4".parse().unwrap()));
+    /// ```
+    pub fn powd(self, exp: Self) -> Result<Self, DecimalError<I>> {
+        if self.0 < I::ZERO {
+            return Err(DecimalError::Undefined { op: Operation::Pow, value: self.0 });
+        }
+        if self.0 == I::ZERO {
+            return match exp.0.cmp(&I::ZERO) {
+                std::cmp::Ordering::Greater => Ok(Self::ZERO),
+                std::cmp::Ordering::Equal => Ok(Self::ONE),
+                std::cmp::Ordering::Less => Err(DecimalError::DivisionByZero {
+                    op: Operation::Pow,
+                    lhs: I::SCALING_FACTOR,
+                    rhs: self.0,
+                }),
+            };
+        }
+
+        let (_, magnitude) = sign_magnitude(self.0);
+        Fixed::ln(magnitude, D)
+            .mul_decimal(exp.0)
+            .exp()
+            .and_then(Fixed::to_decimal)
+            .map(Decimal)
+            .ok_or(DecimalError::UnaryOverflow { op: Operation::Pow, value: self.0 })
+    }
+
+    /// Computes `ln(self) / ln(base)`, where `base` is given as `ln(base)`.
+    fn log(self, op: Operation, ln_base: Option<U512>) -> Result<Self, DecimalError<I>> {
+        if self.0 <= I::ZERO {
+            return Err(DecimalError::Undefined { op, value: self.0 });
+        }
+
+        let (_, magnitude) = sign_magnitude(self.0);
+        let ln = Fixed::ln(magnitude, D);
+        let out = match ln_base {
+            Some(ln_base) => ln.div_constant(ln_base),
+            None => ln,
+        };
+
+        out.to_decimal()
+            .map(Decimal)
+            .ok_or(DecimalError::UnaryOverflow { op, value: self.0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use malachite::base::num::arithmetic::traits::Pow;
+    use malachite::{Float, Integer, Rational};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::macros::generate_tests_for_common_variants;
+    use crate::rounding::reference_round;
+
+    /// Bits of precision used for reference values.
+    const REFERENCE_PRECISION: u64 = 512;
+
+    #[test]
+    fn constants() {
+        let digits = |s: &str| U512::from_str(s).unwrap();
+
+        assert_eq!(SCALE, pow10(PRECISION));
+        assert_eq!(LN2, digits("6931471805599453094172321214581765680755001343602552541206800095"));
+        assert_eq!(
+            LN10,
+            digits("23025850929940456840179914546843642076011014886287729760333279010")
+        );
+    }
+
+    #[test]
+    fn exact_logarithms() {
+        let thousand = "1000".parse::<Decimal<i128, 18>>().unwrap();
+        let milli = "0.001".parse::<Decimal<i128, 18>>().unwrap();
+        let eight = "8".parse::<Decimal<u64, 9>>().unwrap();
+
+        assert_eq!(thousand.log10(), Ok("3".parse().unwrap()));
+        assert_eq!(milli.log10(), Ok("-3".parse().unwrap()));
+        assert_eq!(eight.log2(), Ok("3".parse().unwrap()));
+        assert_eq!(Decimal::<u64, 9>::ONE.ln(), Ok(Decimal::ZERO));
+        assert_eq!(Decimal::<u64, 9>::ZERO.exp(), Ok(Decimal::ONE));
+    }
+
+    #[test]
+    fn domain_errors() {
+        let value = Decimal::<i64, 9>(-1);
+
+        assert_eq!(value.ln(), Err(DecimalError::Undefined { op: Operation::Ln, value: -1 }));
+        assert_eq!(
+            Decimal::<i64, 9>::ZERO.log2(),
+            Err(DecimalError::Undefined { op: Operation::Log2, value: 0 })
+        );
+        assert_eq!(
+            value.powd(Decimal::ONE),
+            Err(DecimalError::Undefined { op: Operation::Pow, value: -1 })
+        );
+        assert_eq!(
+            Decimal::<u64, 9>(1).ln(),
+            Err(DecimalError::UnaryOverflow { op: Operation::Ln, value: 1 }),
+        );
+        assert_eq!(
+            Decimal::<i64, 9>::MAX.exp(),
+            Err(DecimalError::UnaryOverflow { op: Operation::Exp, value: i64::MAX }),
+        );
+        assert_eq!(Decimal::<i64, 9>::MIN.exp(), Ok(Decimal::ZERO));
+    }
+
+    #[test]
+    fn powd_zero_base() {
+        let zero = Decimal::<i64, 9>::ZERO;
+
+        assert_eq!(zero.powd(Decimal::ONE), Ok(zero));
+        assert_eq!(zero.powd(zero), Ok(Decimal::ONE));
+        assert_eq!(
+            zero.powd(-Decimal::ONE),
+            Err(DecimalError::DivisionByZero { op: Operation::Pow, lhs: 1_000_000_000, rhs: 0 }),
+        );
+    }
+
+    /// Rounds `exact` (scaled to the decimal's precision) and asserts the
+    /// result is within 1 ULP of `out` or, on error, that it does not fit.
+    fn assert_within_ulp<I, const D: u8>(
+        out: Result<Decimal<I, D>, DecimalError<I>>,
+        exact: &Rational,
+    ) where
+        I: ScaledInteger<D> + Into<Integer>,
+    {
+        let scaling = <Rational as From<Integer>>::from(I::SCALING_FACTOR.into());
+        let reference = reference_round(&(exact * scaling), RoundingMode::HalfEven);
+        match out {
+            Ok(out) => {
+                let out: Integer = out.0.into();
+                let diff = &out - &reference;
+                assert!((-1..=1).contains(&diff), "out={out}; reference={reference}");
+            }
+            Err(_) => {
+                let min: Integer = I::min_value().into();
+                let max: Integer = I::max_value().into();
+                assert!(reference <= min || reference >= max, "reference={reference}");
+            }
+        }
+    }
+
+    fn differential_fuzz_log<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer>,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>)| {
+            if a.0 <= I::ZERO {
+                assert!(matches!(a.ln(), Err(DecimalError::Undefined { .. })));
+
+                return Ok(());
+            }
+
+            let x = Rational::from(a);
+            let reference = |f: fn(Rational, u64) -> (Float, std::cmp::Ordering)| {
+                Rational::try_from(&f(x.clone(), REFERENCE_PRECISION).0).unwrap()
+            };
+
+            assert_within_ulp(a.ln(), &reference(Float::ln_rational_prec));
+            assert_within_ulp(a.log10(), &reference(Float::log_base_10_rational_prec));
+            assert_within_ulp(a.log2(), &reference(Float::log_base_2_rational_prec));
+        });
+    }
+
+    fn differential_fuzz_exp<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer>,
+        Rational: From<Decimal<I, D>>,
+    {
+        let scaling = <Rational as From<Integer>>::from(I::SCALING_FACTOR.into());
+        let ln = |raw: Integer| {
+            let value = <Rational as From<Integer>>::from(raw) / &scaling;
+
+            Rational::try_from(&Float::ln_rational_prec(value, REFERENCE_PRECISION).0).unwrap()
+        };
+
+        proptest!(|(a: Decimal<I, D>)| {
+            let x = Rational::from(a);
+
+            // `e^x` lies within 1 ULP of `out` iff `x` lies between the logarithms
+            // of the neighbouring values.
+            match a.exp() {
+                Ok(out) => {
+                    let out: Integer = out.0.into();
+                    if out > 1 {
+                        assert!(ln(&out - Integer::from(1)) <= x);
+                    }
+                    assert!(x <= ln(out + Integer::from(1)));
+                }
+                Err(_) => assert!(x > ln(I::max_value().into())),
+            }
+        });
+    }
+
+    fn differential_fuzz_powd<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer>,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, n in 0u64..4)| {
+            if a.0 < I::ZERO {
+                return Ok(());
+            }
+
+            let exp = Decimal::<I, D>(I::SCALING_FACTOR * num_traits::cast(n).unwrap());
+            assert_within_ulp(a.powd(exp), &Rational::from(a).pow(n));
+        });
+    }
+
+    fn differential_fuzz_powd_fractional<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer>,
+        Rational: From<Decimal<I, D>>,
+    {
+        let scaling = <Rational as From<Integer>>::from(I::SCALING_FACTOR.into());
+        let ln = |raw: Integer| {
+            let value = <Rational as From<Integer>>::from(raw) / &scaling;
+
+            Rational::try_from(&Float::ln_rational_prec(value, REFERENCE_PRECISION).0).unwrap()
+        };
+        let bound = I::SCALING_FACTOR * num_traits::cast(4).unwrap();
+
+        proptest!(|(a: Decimal<I, D>, exp: Decimal<I, D>)| {
+            if a.0 <= I::ZERO {
+                return Ok(());
+            }
+
+            // Fold the exponent into `(-4, 4)`, keeping its fractional digits
+            // and (for signed backings) its sign.
+            let exp = Decimal(exp.0 % bound);
+            let y = ln(a.0.into()) * Rational::from(exp);
+
+            // `a^exp` lies within 1 ULP of `out` iff `exp * ln(a)` lies between
+            // the logarithms of the neighbouring values.
+            match a.powd(exp) {
+                Ok(out) => {
+                    let out: Integer = out.0.into();
+                    if out > 1 {
+                        assert!(ln(&out - Integer::from(1)) <= y, "a={a}; exp={exp}; out={out}");
+                    }
+                    assert!(y <= ln(&out + Integer::from(1)), "a={a}; exp={exp}; out={out}");
+                }
+                Err(_) => {
+                    let max: Integer = I::max_value().into();
+                    assert!(y > ln(max - Integer::from(1)), "a={a}; exp={exp}");
+                }
+            }
+        });
+    }
+
+    generate_tests_for_common_variants!(differential_fuzz_log);
+    generate_tests_for_common_variants!(differential_fuzz_exp);
+    generate_tests_for_common_variants!(differential_fuzz_powd);
+    generate_tests_for_common_variants!(differential_fuzz_powd_fractional);
+}
@@ -0,0 +1,235 @@
+use ruint::aliases::U512;
+use thiserror::Error;
+
+use crate::full_mul_div::{from_sign_magnitude, sign_magnitude};
+use crate::{Decimal, ScaledInteger};
+
+/// Error returned when a total cannot be allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum AllocationError {
+    #[error("Allocation weights sum to zero")]
+    ZeroWeight,
+    #[error("Allocation weights cannot be negative")]
+    NegativeWeight,
+}
+
+/// Decides which parts receive the leftover units when their remainders tie.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TieBreak {
+    /// Earlier parts receive leftover units first.
+    #[default]
+    First,
+    /// Later parts receive leftover units first.
+    Last,
+}
+
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    /// Splits `self` in proportion to `weights` using the largest remainder
+    /// method at the smallest unit of the type (`Decimal(1)`).
+    ///
+    /// Every part is first rounded toward zero; the units lost doing so are
+    /// then handed out one each to the parts with the largest discarded
+    /// remainders, with ties resolved by `tie_break`. The parts therefore
+    /// always sum exactly to `self` and share its sign.
+    ///
+    /// Returns an error if any weight is negative or all weights are zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, TieBreak};
+    ///
+    /// let fee = "-0.05".parse::<Decimal<i64, 2>>().unwrap();
+    /// let weights = ["1", "1", "1"].map(|w| w.parse::<Decimal<i64, 2>>().unwrap());
+    ///
+    /// let parts = fee.allocate(&weights, TieBreak::First).unwrap();
+    /// assert_eq!(parts, [Decimal(-2), Decimal(-2), Decimal(-1)]);
+    /// ```
+    pub fn allocate(
+        self,
+        weights: &[Self],
+        tie_break: TieBreak,
+    ) -> Result<Vec<Self>, AllocationError> {
+        let weights = weights
+            .iter()
+            .map(|weight| match sign_magnitude(weight.0) {
+                (true, _) => Err(AllocationError::NegativeWeight),
+                (false, magnitude) => Ok(magnitude),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.allocate_units(&weights, tie_break)
+    }
+
+    /// Splits `self` into `n` parts that differ by at most one unit
+    /// (`Decimal(1)`) and sum exactly to `self`.
+    ///
+    /// The leftover units go to the first or last parts according to
+    /// `tie_break`. Returns an error if `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, TieBreak};
+    ///
+    /// let fill = "10".parse::<Decimal<u64, 2>>().unwrap();
+    /// let parts = fill.split_evenly(3, TieBreak::Last).unwrap();
+    ///
+    /// assert_eq!(parts, [Decimal(333), Decimal(333), Decimal(334)]);
+    /// ```
+    pub fn split_evenly(self, n: usize, tie_break: TieBreak) -> Result<Vec<Self>, AllocationError> {
+        self.allocate_units(&vec![1; n], tie_break)
+    }
+
+    fn allocate_units(
+        self,
+        weights: &[u128],
+        tie_break: TieBreak,
+    ) -> Result<Vec<Self>, AllocationError> {
+        let total_weight = weights
+            .iter()
+            .fold(U512::ZERO, |acc, weight| acc + U512::from(*weight));
+        if total_weight.is_zero() {
+            return Err(AllocationError::ZeroWeight);
+        }
+
+        let (negative, total) = sign_magnitude(self.0);
+        let (mut shares, remainders): (Vec<_>, Vec<_>) = weights
+            .iter()
+            .map(|weight| (U512::from(total) * U512::from(*weight)).div_rem(total_weight))
+            .unzip();
+
+        // Each truncated share loses less than one unit, so fewer units than
+        // there are parts are left over.
+        let allocated = shares.iter().fold(U512::ZERO, |acc, share| acc + share);
+        let leftover = (U512::from(total) - allocated).to::<usize>();
+        let mut order: Vec<_> = (0..weights.len()).collect();
+        order.sort_by(|&a, &b| {
+            let tie = match tie_break {
+                TieBreak::First => a.cmp(&b),
+                TieBreak::Last => b.cmp(&a),
+            };
+
+            remainders[b].cmp(&remainders[a]).then(tie)
+        });
+        for &index in &order[..leftover] {
+            shares[index] += U512::ONE;
+        }
+
+        Ok(shares
+            .into_iter()
+            .map(|share| {
+                from_sign_magnitude(negative, share.to::<u128>())
+                    .map(Decimal)
+                    .expect("shares cannot exceed the total")
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::base::num::arithmetic::traits::Abs;
+    use malachite::{Integer, Rational};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::RoundingMode;
+    use crate::macros::generate_tests_for_common_variants;
+    use crate::rounding::reference_round;
+
+    #[test]
+    fn allocate_errors() {
+        let total = Decimal::<i64, 2>(100);
+
+        assert_eq!(total.allocate(&[], TieBreak::First), Err(AllocationError::ZeroWeight));
+        assert_eq!(
+            total.allocate(&[Decimal::ZERO, Decimal::ZERO], TieBreak::First),
+            Err(AllocationError::ZeroWeight),
+        );
+        assert_eq!(
+            total.allocate(&[Decimal::ONE, Decimal(-1)], TieBreak::First),
+            Err(AllocationError::NegativeWeight),
+        );
+        assert_eq!(total.split_evenly(0, TieBreak::First), Err(AllocationError::ZeroWeight));
+    }
+
+    #[test]
+    fn allocate_largest_remainder() {
+        // Exact shares are 3.5, 2.1 & 1.4 units.
+        let weights = [Decimal::<i64, 1>(50), Decimal(30), Decimal(20)];
+        let total = Decimal::<i64, 1>(7);
+
+        assert_eq!(
+            total.allocate(&weights, TieBreak::First),
+            Ok(vec![Decimal(4), Decimal(2), Decimal(1)])
+        );
+        assert_eq!(
+            (-total).allocate(&weights, TieBreak::Last),
+            Ok(vec![Decimal(-4), Decimal(-2), Decimal(-1)]),
+        );
+        assert_eq!(
+            Decimal::<i8, 0>::MIN.split_evenly(3, TieBreak::First),
+            Ok(vec![Decimal(-43), Decimal(-43), Decimal(-42)]),
+        );
+        assert_eq!(
+            Decimal::<u128, 0>::MAX.allocate(&[Decimal::MAX, Decimal::MAX], TieBreak::Last),
+            Ok(vec![Decimal(u128::MAX / 2), Decimal(u128::MAX / 2 + 1)]),
+        );
+    }
+
+    fn differential_fuzz_allocate<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        // Negative weights are rejected, so flip them to keep the search useful.
+        let weights =
+            proptest::collection::vec(any::<Decimal<I, D>>(), 1..12).prop_map(|weights| {
+                weights
+                    .into_iter()
+                    .map(|weight| match weight.0 < I::ZERO {
+                        true => Decimal(!weight.0),
+                        false => weight,
+                    })
+                    .collect::<Vec<_>>()
+            });
+        let tie_break = prop_oneof![Just(TieBreak::First), Just(TieBreak::Last)];
+        proptest!(|(total: Decimal<I, D>, weights in weights, tie_break in tie_break)| {
+            let Ok(parts) = total.allocate(&weights, tie_break) else {
+                assert!(weights.iter().all(|weight| weight.0 == I::ZERO));
+
+                return Ok(());
+            };
+
+            let total: Integer = total.0.into();
+            let total_weight: Integer = weights.iter().map(|weight| weight.0.into()).sum();
+            let parts: Vec<Integer> = parts.into_iter().map(|part| part.0.into()).collect();
+            assert_eq!(parts.iter().sum::<Integer>(), total);
+
+            // Every part is its exact share rounded either toward or away from zero,
+            // and only parts with the largest remainders may round away.
+            let mut away = Vec::new();
+            let mut toward = Vec::new();
+            for (part, weight) in parts.into_iter().zip(&weights) {
+                let exact = Rational::from_integers(&total * weight.0.into(), total_weight.clone());
+                let truncated = reference_round(&exact, RoundingMode::TowardZero);
+                let remainder = (&exact - Rational::from(truncated.clone())).abs();
+                match part == truncated {
+                    true => toward.push(remainder),
+                    false => {
+                        assert_eq!(part, reference_round(&exact, RoundingMode::AwayFromZero));
+                        away.push(remainder);
+                    }
+                }
+            }
+            if let (Some(away), Some(toward)) = (away.iter().min(), toward.iter().max()) {
+                assert!(away >= toward);
+            }
+        });
+    }
+
+    generate_tests_for_common_variants!(differential_fuzz_allocate);
+}
@@ -5,35 +5,64 @@ pub trait Cheats<const D: u8> {
     const MAX: Self;
     const TWO: Self;
     const TEN: Self;
+    /// The largest scale whose scaling factor fits the integer.
+    const MAX_SCALE: u8;
+    /// Fails to compile when evaluated for a scale above [`Self::MAX_SCALE`].
+    ///
+    /// Every path that depends on the scale (including the `const fn` API &
+    /// `dec!`) reads this, so an unsupported scale is always rejected.
+    const ASSERT_SCALE: ();
     const SCALING_FACTOR: Self;
     const TWO_SCALING_FACTOR: Self;
 }
 
 macro_rules! impl_primitive {
-    ($primitive:tt) => {
+    ($primitive:tt, $max_scale:literal) => {
         impl<const D: u8> Cheats<D> for $primitive {
             const MIN: Self = Self::MIN;
             const MAX: Self = Self::MAX;
             const TWO: Self = 2;
             const TEN: Self = 10;
+            const MAX_SCALE: u8 = $max_scale;
+            const ASSERT_SCALE: () = assert!(
+                D <= $max_scale,
+                concat!(
+                    "`Decimal<",
+                    stringify!($primitive),
+                    ", D>` supports a scale of at most ",
+                    $max_scale,
+                ),
+            );
             paste! {
-                const SCALING_FACTOR: Self = [<10 $primitive>].pow(D as u32);
-                const TWO_SCALING_FACTOR: Self = 2 * [<10 $primitive>].pow(D as u32);
+                const SCALING_FACTOR: Self = {
+                    let () = <Self as Cheats<D>>::ASSERT_SCALE;
+
+                    [<10 $primitive>].pow(D as u32)
+                };
             }
+            const TWO_SCALING_FACTOR: Self =
+                match <Self as Cheats<D>>::SCALING_FACTOR.checked_mul(2) {
+                    Some(two) => two,
+                    None => panic!(concat!(
+                        "`Decimal<",
+                        stringify!($primitive),
+                        ", D>::TWO` does not fit the backing integer at this scale",
+                    )),
+                };
         }
     };
 }
 
-impl_primitive!(u8);
-impl_primitive!(i8);
-impl_primitive!(u16);
-impl_primitive!(i16);
-impl_primitive!(u32);
-impl_primitive!(i32);
-impl_primitive!(u64);
-impl_primitive!(i64);
-impl_primitive!(u128);
-impl_primitive!(i128);
+impl_primitive!(u8, 2);
+impl_primitive!(i8, 2);
+impl_primitive!(u16, 4);
+impl_primitive!(i16, 4);
+impl_primitive!(u32, 9);
+impl_primitive!(i32, 9);
+impl_primitive!(u64, 19);
+impl_primitive!(i64, 18);
+impl_primitive!(u128, 38);
+impl_primitive!(i128, 38);
 
 #[cfg(test)]
 mod tests {
@@ -51,4 +80,31 @@ mod tests {
         assert_eq!(<i64 as Cheats<2>>::SCALING_FACTOR, 100);
         assert_eq!(<i64 as Cheats<3>>::SCALING_FACTOR, 1000);
     }
+
+    #[test]
+    fn max_scale() {
+        macro_rules! assert_max_scale {
+            ($($primitive:ty),+) => {
+                $(
+                    assert_eq!(
+                        u32::from(<$primitive as Cheats<0>>::MAX_SCALE),
+                        <$primitive>::MAX.ilog10(),
+                    );
+                )+
+            };
+        }
+
+        assert_max_scale!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+        assert_eq!(<u64 as Cheats<19>>::SCALING_FACTOR, 10u64.pow(19));
+        assert_eq!(<i128 as Cheats<38>>::SCALING_FACTOR, 10i128.pow(38));
+    }
+
+    #[test]
+    fn two_scaling_factor() {
+        assert_eq!(<u8 as Cheats<2>>::TWO_SCALING_FACTOR, 200);
+        assert_eq!(<i8 as Cheats<1>>::TWO_SCALING_FACTOR, 20);
+        assert_eq!(<u64 as Cheats<18>>::TWO_SCALING_FACTOR, 2 * 10u64.pow(18));
+        assert_eq!(<i128 as Cheats<37>>::TWO_SCALING_FACTOR, 2 * 10i128.pow(37));
+        assert_eq!(<u128 as Cheats<38>>::TWO_SCALING_FACTOR, 2 * 10u128.pow(38));
+    }
 }
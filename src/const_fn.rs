@@ -0,0 +1,357 @@
+use crate::cheats::Cheats;
+use crate::{Decimal, RoundingMode};
+
+/// Splits a decimal string into its sign, its digits (ignoring the decimal
+/// point) & the number of fractional digits.
+///
+/// Accepts an optional leading `-`, at least one integer digit and, after an
+/// optional decimal point, at least one fractional digit. Underscores between
+/// digits are skipped when `underscores` is set (as in Rust literals).
+pub(crate) const fn split_decimal(bytes: &[u8], underscores: bool) -> Option<(bool, u128, u8)> {
+    let negative = !bytes.is_empty() && bytes[0] == b'-';
+    let mut index = negative as usize;
+
+    let mut digits: u128 = 0;
+    let mut integer: usize = 0;
+    let mut fractional: u8 = 0;
+    let mut point = false;
+    while index < bytes.len() {
+        match bytes[index] {
+            digit @ b'0'..=b'9' => {
+                digits = match digits.checked_mul(10) {
+                    Some(digits) => match digits.checked_add((digit - b'0') as u128) {
+                        Some(digits) => digits,
+                        None => return None,
+                    },
+                    None => return None,
+                };
+                match point {
+                    true => match fractional.checked_add(1) {
+                        Some(next) => fractional = next,
+                        None => return None,
+                    },
+                    false => integer += 1,
+                }
+            }
+            b'_' if underscores => {}
+            b'.' if !point => point = true,
+            _ => return None,
+        }
+        index += 1;
+    }
+
+    match integer > 0 && (!point || fractional > 0) {
+        true => Some((negative, digits, fractional)),
+        false => None,
+    }
+}
+
+/// Scales `digits` with `fractional` decimal places to `decimals` places,
+/// returning `None` if digits would be lost or the result overflows.
+pub(crate) const fn scale_digits(digits: u128, fractional: u8, decimals: u8) -> Option<u128> {
+    if fractional > decimals {
+        return None;
+    }
+
+    match 10u128.checked_pow((decimals - fractional) as u32) {
+        Some(multiplier) => digits.checked_mul(multiplier),
+        None if digits == 0 => Some(0),
+        None => None,
+    }
+}
+
+/// Computes the full 256-bit product of `lhs` & `rhs` as `(high, low)`.
+const fn widening_mul(lhs: u128, rhs: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (lhs_high, lhs_low) = (lhs >> 64, lhs & MASK);
+    let (rhs_high, rhs_low) = (rhs >> 64, rhs & MASK);
+    let low = lhs_low * rhs_low;
+    let cross_lhs = lhs_low * rhs_high;
+    let cross_rhs = lhs_high * rhs_low;
+    let middle = (low >> 64) + (cross_lhs & MASK) + (cross_rhs & MASK);
+
+    (
+        lhs_high * rhs_high + (cross_lhs >> 64) + (cross_rhs >> 64) + (middle >> 64),
+        (low & MASK) | (middle << 64),
+    )
+}
+
+/// Divides the 256-bit `(high, low)` by a non-zero `div`, truncating, and
+/// returns `None` if the quotient does not fit a `u128`.
+const fn narrowing_div(high: u128, low: u128, div: u128) -> Option<u128> {
+    if high >= div {
+        return None;
+    }
+
+    // Schoolbook long division, one bit at a time.
+    let mut remainder = high;
+    let mut quotient = 0;
+    let mut bit = u128::BITS;
+    while bit > 0 {
+        bit -= 1;
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= div {
+            remainder = remainder.wrapping_sub(div);
+            quotient |= 1;
+        }
+    }
+
+    Some(quotient)
+}
+
+/// Implements the `const fn` API of [`Decimal`] for a primitive backing.
+///
+/// The generic API relies on trait methods that cannot (yet) be called in a
+/// const context, so these mirror it on concrete backings instead, returning
+/// `None` wherever the generic version would error.
+macro_rules! impl_const_fn {
+    ($($int:ty),+) => {
+        $(
+            impl<const D: u8> Decimal<$int, D> {
+                const CONST_SCALING_FACTOR: u128 = <$int as Cheats<D>>::SCALING_FACTOR as u128;
+
+                const fn const_sign_magnitude(value: $int) -> (bool, u128) {
+                    let () = <$int as Cheats<D>>::ASSERT_SCALE;
+
+                    // NB: Every backing but `u128` fits an `i128`.
+                    match <$int>::MIN == 0 {
+                        true => (false, value as u128),
+                        false => ((value as i128) < 0, (value as i128).unsigned_abs()),
+                    }
+                }
+
+                /// Rebuilds a decimal from the sign & magnitude of its raw
+                /// value, returning `None` if it does not fit the backing.
+                pub(crate) const fn const_from_sign_magnitude(
+                    negative: bool,
+                    magnitude: u128,
+                ) -> Option<Self> {
+                    let () = <$int as Cheats<D>>::ASSERT_SCALE;
+
+                    match negative {
+                        true if magnitude <= (<$int>::MIN as i128).unsigned_abs() => {
+                            Some(Decimal((magnitude as $int).wrapping_neg()))
+                        }
+                        false if magnitude <= <$int>::MAX as u128 => {
+                            Some(Decimal(magnitude as $int))
+                        }
+                        _ => None,
+                    }
+                }
+
+                /// Parses a decimal in a const context, producing the same
+                /// value as [`FromStr`](std::str::FromStr).
+                ///
+                /// Returns `None` if `s` is not a decimal number, has more
+                /// than `D` decimal places or does not fit the backing.
+                #[must_use]
+                pub const fn const_from_str(s: &str) -> Option<Self> {
+                    let Some((negative, digits, fractional)) = split_decimal(s.as_bytes(), false)
+                    else {
+                        return None;
+                    };
+                    let Some(magnitude) = scale_digits(digits, fractional, D) else {
+                        return None;
+                    };
+
+                    Self::const_from_sign_magnitude(negative, magnitude)
+                }
+
+                /// Losslessly converts a scaled integer in a const context,
+                /// like [`Self::try_from_scaled`].
+                #[must_use]
+                pub const fn const_from_scaled(integer: $int, scale: u8) -> Option<Self> {
+                    let (negative, magnitude) = Self::const_sign_magnitude(integer);
+                    if scale <= D {
+                        return match scale_digits(magnitude, scale, D) {
+                            Some(magnitude) => Self::const_from_sign_magnitude(negative, magnitude),
+                            None => None,
+                        };
+                    }
+
+                    match 10u128.checked_pow((scale - D) as u32) {
+                        Some(divisor) if magnitude % divisor == 0 => {
+                            Self::const_from_sign_magnitude(negative, magnitude / divisor)
+                        }
+                        None if magnitude == 0 => Some(Decimal(0)),
+                        _ => None,
+                    }
+                }
+
+                /// Adds `rhs` to `self` in a const context, returning `None`
+                /// on overflow.
+                #[must_use]
+                pub const fn const_checked_add(self, rhs: Self) -> Option<Self> {
+                    let () = <$int as Cheats<D>>::ASSERT_SCALE;
+
+                    match self.0.checked_add(rhs.0) {
+                        Some(out) => Some(Decimal(out)),
+                        None => None,
+                    }
+                }
+
+                /// Subtracts `rhs` from `self` in a const context, returning
+                /// `None` on overflow.
+                #[must_use]
+                pub const fn const_checked_sub(self, rhs: Self) -> Option<Self> {
+                    let () = <$int as Cheats<D>>::ASSERT_SCALE;
+
+                    match self.0.checked_sub(rhs.0) {
+                        Some(out) => Some(Decimal(out)),
+                        None => None,
+                    }
+                }
+
+                /// Multiplies `self` by `rhs` in a const context, rounding
+                /// toward zero like [`Self::checked_mul`].
+                ///
+                /// The intermediate product is computed at full width, so only
+                /// the final result needs to fit the backing.
+                #[must_use]
+                pub const fn const_checked_mul(self, rhs: Self) -> Option<Self> {
+                    let (lhs_negative, lhs) = Self::const_sign_magnitude(self.0);
+                    let (rhs_negative, rhs) = Self::const_sign_magnitude(rhs.0);
+                    let (high, low) = widening_mul(lhs, rhs);
+
+                    match narrowing_div(high, low, Self::CONST_SCALING_FACTOR) {
+                        Some(out) => Self::const_from_sign_magnitude(lhs_negative ^ rhs_negative, out),
+                        None => None,
+                    }
+                }
+
+                /// Divides `self` by `rhs` in a const context, rounding toward
+                /// zero like [`Self::checked_div`].
+                ///
+                /// Returns `None` if `rhs` is zero or the result does not fit
+                /// the backing.
+                #[must_use]
+                pub const fn const_checked_div(self, rhs: Self) -> Option<Self> {
+                    let (lhs_negative, lhs) = Self::const_sign_magnitude(self.0);
+                    let (rhs_negative, rhs) = Self::const_sign_magnitude(rhs.0);
+                    if rhs == 0 {
+                        return None;
+                    }
+                    let (high, low) = widening_mul(lhs, Self::CONST_SCALING_FACTOR);
+
+                    match narrowing_div(high, low, rhs) {
+                        Some(out) => Self::const_from_sign_magnitude(lhs_negative ^ rhs_negative, out),
+                        None => None,
+                    }
+                }
+
+                /// Converts to a different precision in a const context,
+                /// rounding according to `mode` like [`Self::rescale`].
+                ///
+                /// Returns `None` if the rescaled value does not fit the
+                /// backing.
+                #[must_use]
+                pub const fn const_rescale<const D2: u8>(
+                    self,
+                    mode: RoundingMode,
+                ) -> Option<Decimal<$int, D2>> {
+                    let (negative, magnitude) = Self::const_sign_magnitude(self.0);
+                    if D2 >= D {
+                        return match scale_digits(magnitude, D, D2) {
+                            Some(out) => Decimal::<$int, D2>::const_from_sign_magnitude(negative, out),
+                            None => None,
+                        };
+                    }
+
+                    let divisor = 10u128.pow((D - D2) as u32);
+                    let (quotient, remainder) = (magnitude / divisor, magnitude % divisor);
+                    let half = match (remainder, divisor - remainder) {
+                        (low, high) if low < high => std::cmp::Ordering::Less,
+                        (low, high) if low == high => std::cmp::Ordering::Equal,
+                        _ => std::cmp::Ordering::Greater,
+                    };
+                    let away = remainder != 0 && mode.rounds_away(negative, quotient & 1 == 1, half);
+
+                    // NB: `quotient < u128::MAX / 10`, so the increment cannot overflow.
+                    Decimal::<$int, D2>::const_from_sign_magnitude(negative, quotient + away as u128)
+                }
+            }
+        )+
+    };
+}
+
+impl_const_fn!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::Int64_9;
+    use crate::macros::apply_to_common_variants;
+
+    const MAKER_FEE: Int64_9 = Int64_9::const_from_str("0.0002").unwrap();
+    const TAKER_FEE: Int64_9 = MAKER_FEE.const_checked_mul(crate::dec!(2.5)).unwrap();
+
+    #[test]
+    fn const_items() {
+        assert_eq!(MAKER_FEE, "0.0002".parse().unwrap());
+        assert_eq!(TAKER_FEE, "0.0005".parse().unwrap());
+    }
+
+    #[test]
+    fn const_edges() {
+        assert_eq!(Int64_9::const_from_str(""), None);
+        assert_eq!(Int64_9::const_from_str("-"), None);
+        assert_eq!(Int64_9::const_from_str("1."), None);
+        assert_eq!(Int64_9::const_from_str(".5"), None);
+        assert_eq!(Int64_9::const_from_str("1_000"), None);
+        assert_eq!(Int64_9::const_from_str("0.0000000001"), None);
+        assert_eq!(Decimal::<i8, 1>::const_from_str("-12.8"), Some(Decimal::MIN));
+        assert_eq!(Decimal::<u8, 0>::const_from_str("-0"), Some(Decimal(0)));
+        for s in ["-12.8", "-12.9", "12.8", "-0", "-0.0", "-0.1", "25.5", "25.6"] {
+            assert_eq!(Decimal::<i8, 1>::const_from_str(s), s.parse().ok());
+            assert_eq!(Decimal::<u8, 1>::const_from_str(s), s.parse().ok());
+        }
+
+        assert_eq!(Decimal::<u128, 0>::const_from_scaled(0, u8::MAX), Some(Decimal(0)));
+        assert_eq!(Decimal::<i8, 0>::const_from_scaled(-120, 1), Some(Decimal(-12)));
+        assert_eq!(Decimal::<i8, 0>::const_from_scaled(-125, 1), None);
+
+        assert_eq!(Decimal::<u128, 0>::MAX.const_checked_mul(Decimal(1)), Some(Decimal::MAX));
+        assert_eq!(Decimal::<i128, 0>::MIN.const_checked_div(Decimal(-1)), None);
+        assert_eq!(
+            Decimal::<i128, 18>::MIN.const_rescale::<0>(RoundingMode::Floor),
+            Some(Decimal(-170_141_183_460_469_231_732))
+        );
+    }
+
+    /// Checks the const API of a backing against its generic counterpart.
+    macro_rules! differential_fuzz_const_fn {
+        ($int:ty, $decimals:literal) => {
+            ::paste::paste! {
+                #[test]
+                fn [<differential_fuzz_const_fn_ $int _ $decimals>]() {
+                    type Dec = Decimal<$int, $decimals>;
+
+                    let scales = 0..=$decimals + 2u8;
+                    proptest!(|(a: Dec, b: Dec, integer: $int, scale in scales, mode: RoundingMode)| {
+                        assert_eq!(Dec::const_from_str(&a.to_string()), Some(a));
+                        assert_eq!(Dec::const_from_scaled(integer, scale), Dec::try_from_scaled(integer, scale));
+                        assert_eq!(a.const_checked_add(b), a.checked_add(b).ok());
+                        assert_eq!(a.const_checked_sub(b), a.checked_sub(b).ok());
+                        assert_eq!(a.const_checked_mul(b), a.checked_mul(b).ok());
+                        assert_eq!(a.const_checked_div(b), a.checked_div(b).ok());
+                        assert_eq!(
+                            a.const_rescale::<{ $decimals - 1 }>(mode),
+                            a.rescale::<{ $decimals - 1 }>(mode).ok(),
+                        );
+                        assert_eq!(
+                            a.const_rescale::<{ $decimals + 1 }>(mode),
+                            a.rescale::<{ $decimals + 1 }>(mode).ok(),
+                        );
+                    });
+                }
+            }
+        };
+    }
+
+    apply_to_common_variants!(differential_fuzz_const_fn);
+}
@@ -1,21 +1,370 @@
-use crate::{Decimal, Int64_9, Int128_18, ScaledInteger, Uint64_9, Uint128_18};
+use std::cmp::{Ordering, min};
 
-// TODO: Implement From generically where the result cannot overflow.
-// TODO: Implement TryFrom generically where the result can overflow.
+use ruint::aliases::{U256, U512};
+use thiserror::Error;
 
-impl From<Uint64_9> for Uint128_18 {
-    fn from(value: Uint64_9) -> Self {
-        // We know this multiplication can never overflow.
-        #[allow(clippy::arithmetic_side_effects)]
-        Decimal((u128::from(value.0)) * 10u128.pow(9))
+use crate::cheats::Cheats;
+use crate::full_mul_div::{from_sign_magnitude, sign_magnitude};
+use crate::rounding::{round_div, round_div_uint};
+use crate::{Decimal, DecimalError, I256, Operation, RoundingMode, ScaledInteger};
+
+/// Error returned when converting a [`Decimal`] to another backing or to a
+/// primitive integer fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_decimal::{Decimal, TryFromDecimalError};
+///
+/// // Widening the backing is lossless as long as the scale does not decrease.
+/// let price = Decimal::<i64, 9>(-1_500_000_000);
+/// assert_eq!(Decimal::<i128, 18>::from(price), Decimal(-1_500_000_000_000_000_000));
+///
+/// // Everything else is fallible.
+/// assert_eq!(Decimal::<u64, 9>::try_from(price), Err(TryFromDecimalError::Overflow));
+/// assert_eq!(Decimal::<i32, 0>::try_from(price), Err(TryFromDecimalError::PrecisionLoss));
+/// assert_eq!(Decimal::<i32, 1>::try_from(price), Ok(Decimal(-15)));
+/// assert_eq!(Decimal::<i128, 6>::try_from(price), Ok(Decimal(-1_500_000)));
+/// ```
+///
+/// `From` is only implemented where the conversion can never fail, so a
+/// decreasing scale does not compile:
+///
+/// ```rust,compile_fail
+/// use const_decimal::Decimal;
+///
+/// let _ = Decimal::<i128, 6>::from(Decimal::<i64, 9>::ONE);
+/// ```
+///
+/// Neither does a scale that does not fit its backing:
+///
+/// ```rust,compile_fail
+/// use const_decimal::Decimal;
+///
+/// let _ = Decimal::<i8, 5>::try_from(Decimal::<u8, 0>(1));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum TryFromDecimalError {
+    #[error("Converted decimal does not fit the target backing integer")]
+    Overflow,
+    #[error("Converted decimal would lose precision")]
+    PrecisionLoss,
+}
+
+/// Implemented by the backing `Self` for each pair of scales where every
+/// `Decimal<I, D1>` converts to `Decimal<Self, D2>` without overflowing, which
+/// bounds the [`From`] conversions between backings.
+pub trait LosslessScale<I, const D1: u8, const D2: u8> {}
+
+/// Implements [`From`] for every pair of supported scales that can never
+/// overflow & [`TryFrom`] (see [`impl_try_from`]) for every other pair.
+///
+/// Takes the supported scales of both backings, with those of the target split
+/// into the scales `Decimal<$from, 0>` converts to losslessly & the rest. Each
+/// source scale `D1` converts losslessly to the same number of target scales
+/// starting at `D1`, so this window slides along the target scales with `D1`.
+macro_rules! impl_from {
+    (@scales $from:ty => $to:ty; []; $below:tt; $lossless:tt; $beyond:tt) => {};
+    (
+        @scales $from:ty => $to:ty;
+        [$d1:literal $($d1s:literal)*];
+        [$($below:literal)*];
+        [$next:literal $($lossless:literal)*];
+        [$($beyond:literal)*]
+    ) => {
+        impl LosslessScale<$from, $d1, $next> for $to {}
+        $(impl LosslessScale<$from, $d1, $lossless> for $to {})*
+        impl_try_from!(@scales $from => $to; $d1; $($below)* $($beyond)*);
+        impl_from!(@shift $from => $to; [$($d1s)*]; [$($below)* $next]; [$($lossless)*]; [$($beyond)*]);
+    };
+    (@shift $from:ty => $to:ty; $d1s:tt; $below:tt; [$($lossless:literal)*]; []) => {
+        impl_from!(@scales $from => $to; $d1s; $below; [$($lossless)*]; []);
+    };
+    (
+        @shift $from:ty => $to:ty;
+        $d1s:tt;
+        $below:tt;
+        [$($lossless:literal)*];
+        [$first:literal $($beyond:literal)*]
+    ) => {
+        impl_from!(@scales $from => $to; $d1s; $below; [$($lossless)* $first]; [$($beyond)*]);
+    };
+    ($from:ty [$($d1:literal)+] => $to:ty [$($lossless:literal)+] [$($beyond:literal)*]) => {
+        impl_from!(@scales $from => $to; [$($d1)+]; []; [$($lossless)+]; [$($beyond)*]);
+
+        impl<const D1: u8, const D2: u8> From<Decimal<$from, D1>> for Decimal<$to, D2>
+        where
+            $to: LosslessScale<$from, D1, D2>,
+        {
+            fn from(value: Decimal<$from, D1>) -> Self {
+                // SAFETY: `LosslessScale` guarantees `D2 >= D1` & that every `$from`
+                // scaled by `10^(D2 - D1)` fits in `$to`.
+                #[allow(clippy::arithmetic_side_effects)]
+                Decimal(<$to>::from(value.0) * <$to>::pow(10, u32::from(D2 - D1)))
+            }
+        }
+    };
+}
+
+/// Implements [`TryFrom`] for backings that cannot hold every value of the
+/// source backing, or for the given scales of a backing that can.
+macro_rules! impl_try_from {
+    (@scales $from:ty => $to:ty; $d1:literal; $($d2:literal)*) => {
+        $(
+            impl TryFrom<Decimal<$from, $d1>> for Decimal<$to, $d2> {
+                type Error = TryFromDecimalError;
+
+                #[inline]
+                fn try_from(value: Decimal<$from, $d1>) -> Result<Self, Self::Error> {
+                    try_convert(value)
+                }
+            }
+        )*
+    };
+    ($from:ty => $($to:ty),+) => {
+        $(
+            impl<const D1: u8, const D2: u8> TryFrom<Decimal<$from, D1>> for Decimal<$to, D2> {
+                type Error = TryFromDecimalError;
+
+                fn try_from(value: Decimal<$from, D1>) -> Result<Self, Self::Error> {
+                    try_convert(value)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(u8 [0 1 2] => u16 [0 1 2] [3 4]);
+impl_from!(u8 [0 1 2] => u32 [0 1 2 3 4 5 6 7] [8 9]);
+impl_from!(u8 [0 1 2] => u64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16] [17 18 19]);
+impl_from!(
+    u8 [0 1 2] => u128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
+     20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36]
+    [37 38]
+);
+impl_from!(u8 [0 1 2] => i16 [0 1 2] [3 4]);
+impl_from!(u8 [0 1 2] => i32 [0 1 2 3 4 5 6] [7 8 9]);
+impl_from!(u8 [0 1 2] => i64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16] [17 18]);
+impl_from!(
+    u8 [0 1 2] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
+     20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35]
+    [36 37 38]
+);
+impl_from!(u16 [0 1 2 3 4] => u32 [0 1 2 3 4] [5 6 7 8 9]);
+impl_from!(u16 [0 1 2 3 4] => u64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14] [15 16 17 18 19]);
+impl_from!(
+    u16 [0 1 2 3 4] => u128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33]
+    [34 35 36 37 38]
+);
+impl_from!(u16 [0 1 2 3 4] => i32 [0 1 2 3 4] [5 6 7 8 9]);
+impl_from!(u16 [0 1 2 3 4] => i64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14] [15 16 17 18]);
+impl_from!(
+    u16 [0 1 2 3 4] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33]
+    [34 35 36 37 38]
+);
+impl_from!(u32 [0 1 2 3 4 5 6 7 8 9] => u64 [0 1 2 3 4 5 6 7 8 9] [10 11 12 13 14 15 16 17 18 19]);
+impl_from!(
+    u32 [0 1 2 3 4 5 6 7 8 9] => u128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28]
+    [29 30 31 32 33 34 35 36 37 38]
+);
+impl_from!(u32 [0 1 2 3 4 5 6 7 8 9] => i64 [0 1 2 3 4 5 6 7 8 9] [10 11 12 13 14 15 16 17 18]);
+impl_from!(
+    u32 [0 1 2 3 4 5 6 7 8 9] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28]
+    [29 30 31 32 33 34 35 36 37 38]
+);
+impl_from!(
+    u64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19] => u128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19]
+    [20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38]
+);
+impl_from!(
+    u64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18]
+    [19 20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38]
+);
+impl_from!(i8 [0 1 2] => i16 [0 1 2] [3 4]);
+impl_from!(i8 [0 1 2] => i32 [0 1 2 3 4 5 6 7] [8 9]);
+impl_from!(i8 [0 1 2] => i64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16] [17 18]);
+impl_from!(
+    i8 [0 1 2] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
+     20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36]
+    [37 38]
+);
+impl_from!(i16 [0 1 2 3 4] => i32 [0 1 2 3 4] [5 6 7 8 9]);
+impl_from!(i16 [0 1 2 3 4] => i64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14] [15 16 17 18]);
+impl_from!(
+    i16 [0 1 2 3 4] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32 33]
+    [34 35 36 37 38]
+);
+impl_from!(i32 [0 1 2 3 4 5 6 7 8 9] => i64 [0 1 2 3 4 5 6 7 8 9] [10 11 12 13 14 15 16 17 18]);
+impl_from!(
+    i32 [0 1 2 3 4 5 6 7 8 9] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28]
+    [29 30 31 32 33 34 35 36 37 38]
+);
+impl_from!(
+    i64 [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18] => i128
+    [0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19]
+    [20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38]
+);
+
+impl_try_from!(u8 => i8);
+impl_try_from!(i8 => u8, u16, u32, u64, u128);
+impl_try_from!(u16 => u8, i8, i16);
+impl_try_from!(i16 => u8, i8, u16, u32, u64, u128);
+impl_try_from!(u32 => u8, i8, u16, i16, i32);
+impl_try_from!(i32 => u8, i8, u16, i16, u32, u64, u128);
+impl_try_from!(u64 => u8, i8, u16, i16, u32, i32, i64);
+impl_try_from!(i64 => u8, i8, u16, i16, u32, i32, u64, u128);
+impl_try_from!(u128 => u8, i8, u16, i16, u32, i32, u64, i64, i128);
+impl_try_from!(i128 => u8, i8, u16, i16, u32, i32, u64, i64, u128);
+
+/// Implements [`TryFrom`] from the exact products of [`Decimal::widening_mul`]
+/// for 128-bit backings back to every primitive backing.
+macro_rules! impl_try_from_wide {
+    ($($to:ty),+) => {
+        $(
+            impl<const D1: u8, const D2: u8> TryFrom<Decimal<U256, D1>> for Decimal<$to, D2> {
+                type Error = TryFromDecimalError;
+
+                fn try_from(value: Decimal<U256, D1>) -> Result<Self, Self::Error> {
+                    try_narrow::<_, D1, D2>(false, value.0)
+                }
+            }
+
+            impl<const D1: u8, const D2: u8> TryFrom<Decimal<I256, D1>> for Decimal<$to, D2> {
+                type Error = TryFromDecimalError;
+
+                fn try_from(value: Decimal<I256, D1>) -> Result<Self, Self::Error> {
+                    try_narrow::<_, D1, D2>(value.0.is_negative(), value.0.unsigned_abs())
+                }
+            }
+        )+
+    };
+}
+
+impl_try_from_wide!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+/// Converts the sign & magnitude of a raw value at scale `D1` to a
+/// `Decimal<I, D2>`.
+fn try_narrow<I, const D1: u8, const D2: u8>(
+    negative: bool,
+    magnitude: U256,
+) -> Result<Decimal<I, D2>, TryFromDecimalError>
+where
+    I: ScaledInteger<D2>,
+{
+    let () = <I as Cheats<D2>>::ASSERT_SCALE;
+
+    let ten = U256::from(10);
+    let magnitude = match D2.cmp(&D1) {
+        Ordering::Less => match ten.checked_pow(U256::from(D1 - D2)) {
+            Some(divisor) if (magnitude % divisor).is_zero() => magnitude / divisor,
+            None if magnitude.is_zero() => magnitude,
+            _ => return Err(TryFromDecimalError::PrecisionLoss),
+        },
+        Ordering::Equal | Ordering::Greater => ten
+            .checked_pow(U256::from(D2 - D1))
+            .and_then(|multiplier| magnitude.checked_mul(multiplier))
+            .ok_or(TryFromDecimalError::Overflow)?,
+    };
+
+    u128::try_from(magnitude)
+        .ok()
+        .and_then(|magnitude| from_sign_magnitude(negative, magnitude))
+        .map(Decimal)
+        .ok_or(TryFromDecimalError::Overflow)
+}
+
+fn try_convert<I1, const D1: u8, I2, const D2: u8>(
+    value: Decimal<I1, D1>,
+) -> Result<Decimal<I2, D2>, TryFromDecimalError>
+where
+    I1: ScaledInteger<D1>,
+    I2: ScaledInteger<D2>,
+{
+    let () = <I1 as Cheats<D1>>::ASSERT_SCALE;
+    let () = <I2 as Cheats<D2>>::ASSERT_SCALE;
+
+    match D2.cmp(&D1) {
+        Ordering::Less => {
+            // NB: `10^(D1 - D2)` cannot exceed `I1::SCALING_FACTOR` as `D1` is
+            // asserted to fit `I1`.
+            let divisor = <I1 as Cheats<D1>>::TEN.pow(u32::from(D1 - D2));
+
+            // SAFETY: `divisor` is a positive power of ten.
+            #[allow(clippy::arithmetic_side_effects)]
+            if value.0 % divisor != I1::ZERO {
+                return Err(TryFromDecimalError::PrecisionLoss);
+            }
+
+            // SAFETY: `divisor` is a positive power of ten.
+            #[allow(clippy::arithmetic_side_effects)]
+            num_traits::cast::<_, I2>(value.0 / divisor)
+                .map(Decimal)
+                .ok_or(TryFromDecimalError::Overflow)
+        }
+        Ordering::Equal | Ordering::Greater => {
+            // NB: `10^(D2 - D1)` cannot exceed `I2::SCALING_FACTOR` as `D2` is
+            // asserted to fit `I2`.
+            let multiplier = <I2 as Cheats<D2>>::TEN.pow(u32::from(D2 - D1));
+
+            num_traits::cast::<_, I2>(value.0)
+                .and_then(|integer| integer.checked_mul(&multiplier))
+                .map(Decimal)
+                .ok_or(TryFromDecimalError::Overflow)
+        }
     }
 }
 
-impl From<Int64_9> for Int128_18 {
-    fn from(value: Int64_9) -> Self {
-        // We know this multiplication can never overflow.
-        #[allow(clippy::arithmetic_side_effects)]
-        Decimal((i128::from(value.0)) * 10i128.pow(9))
+/// Rounds `numer / div` to a float with `precision` significand bits whose
+/// least significant bit is worth at least `2^min_exponent`, returning it as an
+/// (exact) `f64`.
+fn round_to_float(
+    negative: bool,
+    numer: u128,
+    div: u128,
+    precision: u32,
+    min_exponent: i32,
+    mode: RoundingMode,
+) -> f64 {
+    if numer == 0 {
+        return 0.0;
+    }
+
+    // Choose `shift` so that `numer * 2^shift / div` holds `precision` bits,
+    // unless that would take the exponent below the subnormal range.
+    let bits = |x: u128| (u128::BITS - x.leading_zeros()) as i32;
+    let precision_bits = precision as i32;
+    let scaled = |shift: i32| match shift.is_negative() {
+        true => (U512::from(numer), U512::from(div) << shift.unsigned_abs() as usize),
+        false => (U512::from(numer) << shift as usize, U512::from(div)),
+    };
+    let mut shift = precision_bits + bits(div) - bits(numer);
+    let (scaled_numer, scaled_div) = scaled(shift);
+    if (scaled_numer / scaled_div).bit_len() > precision as usize {
+        shift -= 1;
+    }
+    let shift = min(shift, -min_exponent);
+    let (scaled_numer, scaled_div) = scaled(shift);
+
+    // NB: The significand is at most `2^precision`, which `f64` holds exactly,
+    // and `2^-shift` is a normal `f64` for every supported target.
+    let significand = round_div_uint(scaled_numer, scaled_div, negative, mode).to::<u64>();
+    let power = f64::from_bits(((1023 - shift) as u64) << 52);
+    let magnitude = significand as f64 * power;
+
+    match negative {
+        true => -magnitude,
+        false => magnitude,
     }
 }
 
@@ -23,15 +372,462 @@ impl<I, const D: u8> Decimal<I, D>
 where
     I: ScaledInteger<D>,
 {
-    // SAFETY: `num_traits::to_f64` does not panic on primitive types.
-    #[allow(clippy::missing_panics_doc)]
+    /// Converts to the nearest `f64`, with ties rounded to even.
     pub fn to_f64(&self) -> f64 {
-        self.0.to_f64().unwrap() / I::SCALING_FACTOR.to_f64().unwrap()
+        self.to_f64_round(RoundingMode::HalfEven)
     }
 
-    #[allow(clippy::missing_panics_doc)]
+    /// Converts to the nearest `f32`, with ties rounded to even.
     pub fn to_f32(&self) -> f32 {
-        self.0.to_f32().unwrap() / I::SCALING_FACTOR.to_f32().unwrap()
+        self.to_f32_round(RoundingMode::HalfEven)
+    }
+
+    /// Converts to an `f64` rounded once according to `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let third = Decimal::<u128, 18>(333_333_333_333_333_333);
+    ///
+    /// assert_eq!(third.to_f64_round(RoundingMode::Floor), 0.333_333_333_333_333_3);
+    /// assert_eq!(third.to_f64_round(RoundingMode::Ceiling), 0.333_333_333_333_333_37);
+    /// ```
+    #[must_use]
+    pub fn to_f64_round(&self, mode: RoundingMode) -> f64 {
+        let (negative, magnitude) = sign_magnitude(self.0);
+
+        // Both operands are exact & IEEE-754 division rounds once to nearest.
+        if mode == RoundingMode::HalfEven && magnitude < 1 << 53 && D <= 22 {
+            return self.0.to_f64().unwrap() / I::SCALING_FACTOR.to_f64().unwrap();
+        }
+
+        round_to_float(negative, magnitude, sign_magnitude(I::SCALING_FACTOR).1, 53, -1074, mode)
+    }
+
+    /// Converts to an `f32` rounded once according to `mode`.
+    #[must_use]
+    pub fn to_f32_round(&self, mode: RoundingMode) -> f32 {
+        let (negative, magnitude) = sign_magnitude(self.0);
+
+        // Both operands are exact & IEEE-754 division rounds once to nearest.
+        if mode == RoundingMode::HalfEven && magnitude < 1 << 24 && D <= 10 {
+            return self.0.to_f32().unwrap() / I::SCALING_FACTOR.to_f32().unwrap();
+        }
+
+        // NB: The result is representable as an `f32` (or overflows to infinity
+        // exactly when the rounded value does), so narrowing is exact.
+        round_to_float(negative, magnitude, sign_magnitude(I::SCALING_FACTOR).1, 24, -149, mode)
+            as f32
+    }
+}
+
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    /// Converts to a different precision, rounding according to `mode` when
+    /// the target has fewer decimals.
+    ///
+    /// Returns an error if the rescaled value does not fit the backing integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let d = "1.234567891".parse::<Decimal<i64, 9>>().unwrap();
+    /// let out = d.rescale::<6>(RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(out, "1.234568".parse::<Decimal<i64, 6>>().unwrap());
+    /// ```
+    ///
+    /// A target scale that does not fit the backing fails to compile:
+    ///
+    /// ```rust,compile_fail
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let _ = Decimal::<u8, 0>(1).rescale::<3>(RoundingMode::HalfEven);
+    /// ```
+    pub fn rescale<const D2: u8>(
+        self,
+        mode: RoundingMode,
+    ) -> Result<Decimal<I, D2>, DecimalError<I>>
+    where
+        I: ScaledInteger<D2>,
+    {
+        let () = <I as Cheats<D2>>::ASSERT_SCALE;
+
+        match D2 < D {
+            true => Ok(Decimal(round_div(self.0, Self::rescale_factor::<D2>(), mode))),
+            false => self.widen_scale(),
+        }
+    }
+
+    /// Losslessly converts to a different precision.
+    ///
+    /// Returns an error if the target has fewer decimals and a non-zero digit
+    /// would be discarded, or if the rescaled value does not fit the backing
+    /// integer.
+    pub fn try_rescale<const D2: u8>(self) -> Result<Decimal<I, D2>, DecimalError<I>>
+    where
+        I: ScaledInteger<D2>,
+    {
+        let () = <I as Cheats<D2>>::ASSERT_SCALE;
+
+        match D2 < D {
+            true => {
+                let divisor = Self::rescale_factor::<D2>();
+
+                // SAFETY: `divisor` is a positive power of ten.
+                #[allow(clippy::arithmetic_side_effects)]
+                match self.0 % divisor == I::ZERO {
+                    true => Ok(Decimal(self.0 / divisor)),
+                    false => {
+                        Err(DecimalError::PrecisionLoss { op: Operation::Rescale, value: self.0 })
+                    }
+                }
+            }
+            false => self.widen_scale(),
+        }
+    }
+
+    /// Converts to a precision with at least as many decimals, wrapping around
+    /// the bounds of the backing integer on overflow.
+    ///
+    /// Narrowing the scale (`D2 < D`) is rejected at compile time; use
+    /// [`Self::rescale`] or [`Self::try_rescale`] instead.
+    #[must_use]
+    pub fn rescale_exact<const D2: u8>(self) -> Decimal<I, D2>
+    where
+        I: ScaledInteger<D2>,
+    {
+        const { assert!(D2 >= D, "`rescale_exact` cannot narrow the scale") };
+        let () = <I as Cheats<D2>>::ASSERT_SCALE;
+
+        Decimal(self.0.wrapping_mul(&Self::rescale_factor::<D2>()))
+    }
+
+    fn widen_scale<const D2: u8>(self) -> Result<Decimal<I, D2>, DecimalError<I>>
+    where
+        I: ScaledInteger<D2>,
+    {
+        let () = <I as Cheats<D2>>::ASSERT_SCALE;
+        let multiplier = Self::rescale_factor::<D2>();
+
+        self.0
+            .checked_mul(&multiplier)
+            .map(Decimal)
+            .ok_or(DecimalError::Overflow { op: Operation::Rescale, lhs: self.0, rhs: multiplier })
+    }
+
+    /// `10^|D2 - D|`, which always fits as it cannot exceed the larger
+    /// scaling factor once both scales are asserted to fit the backing.
+    fn rescale_factor<const D2: u8>() -> I
+    where
+        I: ScaledInteger<D2>,
+    {
+        let () = <I as Cheats<D>>::ASSERT_SCALE;
+        let () = <I as Cheats<D2>>::ASSERT_SCALE;
+
+        <I as Cheats<D>>::TEN.pow(u32::from(D.abs_diff(D2)))
+    }
+}
+
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    /// Rounds to an integer using `mode`, returning the unscaled backing
+    /// integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let d = "-2.5".parse::<Decimal<i64, 9>>().unwrap();
+    /// assert_eq!(d.to_integer(RoundingMode::TowardZero), -2);
+    /// assert_eq!(d.to_integer(RoundingMode::Floor), -3);
+    /// assert_eq!(d.to_integer(RoundingMode::HalfEven), -2);
+    /// ```
+    #[must_use]
+    pub fn to_integer(self, mode: RoundingMode) -> I {
+        round_div(self.0, I::SCALING_FACTOR, mode)
+    }
+
+    /// Splits `self` into its sign, its integer part (rounded toward zero) and
+    /// the magnitude of its fractional part in units of `10^-D`, the same way
+    /// [`Display`](std::fmt::Display) prints them.
+    ///
+    /// The sign is returned separately as values in `(-1, 0)` have a zero
+    /// integer part.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::Decimal;
+    ///
+    /// let d = "-12.034".parse::<Decimal<i64, 3>>().unwrap();
+    /// assert_eq!(d.into_parts(), (true, -12, 34));
+    /// assert_eq!(Decimal::from_parts(true, -12, 34), Some(d));
+    ///
+    /// let d = "-0.034".parse::<Decimal<i64, 3>>().unwrap();
+    /// assert_eq!(d.into_parts(), (true, 0, 34));
+    /// assert_eq!(Decimal::from_parts(true, 0, 34), Some(d));
+    /// ```
+    #[must_use]
+    pub fn into_parts(self) -> (bool, I, I) {
+        // SAFETY: `SCALING_FACTOR` cannot be zero.
+        #[allow(clippy::arithmetic_side_effects)]
+        let (integer, fractional) = (self.0 / I::SCALING_FACTOR, self.0 % I::SCALING_FACTOR);
+
+        // Only negate the remainder, whose magnitude is less than the positive scaling
+        // factor and therefore always representable.
+        match fractional < I::ZERO {
+            true => (true, integer, (!fractional).wrapping_add(&I::ONE)),
+            false => (self.0 < I::ZERO, integer, fractional),
+        }
+    }
+
+    /// Builds a decimal from its sign, integer part and the magnitude of its
+    /// fractional part in units of `10^-D`, the inverse of
+    /// [`Self::into_parts`].
+    ///
+    /// Returns `None` if `fractional` is not in `[0, SCALING_FACTOR)`, if
+    /// `integer` has the opposite sign of `negative`, or if the result does not
+    /// fit the backing integer.
+    pub fn from_parts(negative: bool, integer: I, fractional: I) -> Option<Self> {
+        let sign_matches = match negative {
+            true => integer <= I::ZERO,
+            false => integer >= I::ZERO,
+        };
+        if !sign_matches || fractional < I::ZERO || fractional >= I::SCALING_FACTOR {
+            return None;
+        }
+
+        let scaled = integer.checked_mul(&I::SCALING_FACTOR)?;
+        match negative {
+            true => scaled.checked_sub(&fractional),
+            false => scaled.checked_add(&fractional),
+        }
+        .map(Decimal)
+    }
+}
+
+/// Implements [`TryFrom`] from any [`Decimal`] to a primitive integer, failing
+/// if the value has a fractional part or does not fit.
+macro_rules! impl_try_into_integer {
+    ($($to:ty),+) => {
+        $(
+            impl<I, const D: u8> TryFrom<Decimal<I, D>> for $to
+            where
+                I: ScaledInteger<D>,
+            {
+                type Error = TryFromDecimalError;
+
+                fn try_from(value: Decimal<I, D>) -> Result<Self, Self::Error> {
+                    if value.fract() != Decimal::ZERO {
+                        return Err(TryFromDecimalError::PrecisionLoss);
+                    }
+
+                    num_traits::cast(value.to_integer(RoundingMode::TowardZero))
+                        .ok_or(TryFromDecimalError::Overflow)
+                }
+            }
+        )+
+    };
+}
+
+impl_try_into_integer!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+
+/// Error returned when converting a float to a [`Decimal`] fails.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_decimal::{Decimal, RoundingMode, TryFromFloatError};
+///
+/// // `0.1` has no exact binary representation.
+/// assert_eq!(Decimal::<i64, 9>::try_from(0.1), Err(TryFromFloatError::PrecisionLoss));
+/// assert_eq!(Decimal::<i64, 9>::try_from(0.125), Ok(Decimal(125_000_000)));
+/// assert_eq!(Decimal::<i64, 9>::from_f64_round(0.1, RoundingMode::Floor), Ok(Decimal(100_000_000)));
+/// assert_eq!(Decimal::<i64, 9>::from_f64_shortest(0.1), Ok(Decimal(100_000_000)));
+///
+/// assert_eq!(Decimal::<i64, 9>::try_from(f64::NAN), Err(TryFromFloatError::NaN));
+/// assert_eq!(Decimal::<i64, 9>::try_from(f64::INFINITY), Err(TryFromFloatError::Infinite));
+/// assert_eq!(Decimal::<i64, 9>::try_from(1e10), Err(TryFromFloatError::Overflow));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum TryFromFloatError {
+    #[error("Cannot convert NaN to a decimal")]
+    NaN,
+    #[error("Cannot convert an infinite float to a decimal")]
+    Infinite,
+    #[error("Converted float does not fit the target backing integer")]
+    Overflow,
+    #[error("Converted float would lose precision")]
+    PrecisionLoss,
+}
+
+/// Splits a finite float into its sign and an exact `numer / div` of the
+/// scaled value `|x| * 10^D`.
+fn scaled_float<const D: u8>(x: f64) -> Result<(bool, U512, U512), TryFromFloatError> {
+    if x.is_nan() {
+        return Err(TryFromFloatError::NaN);
+    }
+    if x.is_infinite() {
+        return Err(TryFromFloatError::Infinite);
+    }
+
+    // Decode `|x| = mantissa * 2^exponent` from the IEEE-754 bits.
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = bits & ((1 << 52) - 1);
+    let (mantissa, exponent) = match biased {
+        0 => (fraction, -1074),
+        _ => (fraction | (1 << 52), biased - 1075),
+    };
+
+    let numer = U512::from(mantissa) * U512::from(10).pow(U512::from(D));
+    match exponent.is_negative() {
+        // A numerator below `2^181` divided by more than `2^256` rounds like any
+        // other value in `(0, 0.5)`, so clamp the shift to stay in range.
+        true => Ok((x.is_sign_negative(), numer, U512::ONE << min(exponent.unsigned_abs(), 256))),
+        // Any non-zero numerator shifted past `2^256` cannot fit a `u128`.
+        false => match mantissa != 0 && exponent > 256 {
+            true => Err(TryFromFloatError::Overflow),
+            false => Ok((x.is_sign_negative(), numer << exponent as usize, U512::ONE)),
+        },
+    }
+}
+
+/// Splits a finite float into the shortest digits that round-trip to it and
+/// their decimal exponent, i.e. `|x| = digits * 10^exponent`, without
+/// allocating.
+fn shortest_digits(x: f64) -> (u64, i32) {
+    // `ryu` formats into a stack buffer as e.g. `1.0`, `0.001`, `1.25e-7` or
+    // `1e16`.
+    let mut buffer = ryu::Buffer::new();
+    let formatted = buffer.format_finite(x.abs());
+    let (mantissa, exponent) = formatted.split_once('e').unwrap_or((formatted, "0"));
+    let mut exponent: i32 = exponent.parse().expect("`ryu` prints a valid exponent");
+
+    let mut digits = 0u64;
+    let mut fractional = false;
+    for byte in mantissa.bytes() {
+        match byte {
+            b'.' => fractional = true,
+            // SAFETY: `ryu` prints at most 17 significant digits, and only
+            // prints a plain mantissa for exponents below 16, so `digits` stays
+            // below `10^18` & `exponent` within `f64`'s range.
+            #[allow(clippy::arithmetic_side_effects)]
+            digit => {
+                digits = digits * 10 + u64::from(digit - b'0');
+                exponent -= i32::from(fractional);
+            }
+        }
+    }
+
+    // Drop trailing zeros such as the `.0` of `1.0`.
+    // SAFETY: Dividing by ten only shrinks `digits` & `exponent` stays in range.
+    #[allow(clippy::arithmetic_side_effects)]
+    while digits != 0 && digits.is_multiple_of(10) {
+        digits /= 10;
+        exponent += 1;
+    }
+
+    (digits, exponent)
+}
+
+fn narrow_float<I, const D: u8>(
+    negative: bool,
+    magnitude: U512,
+) -> Result<Decimal<I, D>, TryFromFloatError>
+where
+    I: ScaledInteger<D>,
+{
+    u128::try_from(magnitude)
+        .ok()
+        .and_then(|magnitude| from_sign_magnitude(negative, magnitude))
+        .map(Decimal)
+        .ok_or(TryFromFloatError::Overflow)
+}
+
+impl<I, const D: u8> TryFrom<f64> for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Error = TryFromFloatError;
+
+    /// Converts the exact binary value of `value`, failing if it cannot be
+    /// represented without rounding.
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        let (negative, numer, div) = scaled_float::<D>(value)?;
+        let (quotient, remainder) = numer.div_rem(div);
+        if !remainder.is_zero() {
+            return Err(TryFromFloatError::PrecisionLoss);
+        }
+
+        narrow_float(negative, quotient)
+    }
+}
+
+impl<I, const D: u8> TryFrom<f32> for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Error = TryFromFloatError;
+
+    /// Converts the exact binary value of `value`, failing if it cannot be
+    /// represented without rounding.
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        // NB: Widening to `f64` is exact.
+        Self::try_from(f64::from(value))
+    }
+}
+
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    /// Converts the exact binary value of `x`, rounding once according to
+    /// `mode`.
+    pub fn from_f64_round(x: f64, mode: RoundingMode) -> Result<Self, TryFromFloatError> {
+        let (negative, numer, div) = scaled_float::<D>(x)?;
+
+        narrow_float(negative, round_div_uint(numer, div, negative, mode))
+    }
+
+    /// Converts `x` to the shortest decimal that parses back to the same
+    /// `f64`, i.e. the digits printed by `x`'s [`Display`](std::fmt::Display).
+    ///
+    /// Unlike [`TryFrom<f64>`], `0.1` converts to exactly `0.1` rather than the
+    /// nearest binary value. Returns [`TryFromFloatError::PrecisionLoss`] if
+    /// the shortest decimal has more than `D` decimal places.
+    pub fn from_f64_shortest(x: f64) -> Result<Self, TryFromFloatError> {
+        scaled_float::<D>(x)?;
+
+        let (digits, exponent) = shortest_digits(x);
+        if digits == 0 {
+            return Ok(Decimal::ZERO);
+        }
+
+        // `digits * 10^shift` is the scaled value.
+        // SAFETY: `exponent` is within `f64`'s range.
+        #[allow(clippy::arithmetic_side_effects)]
+        let shift = exponent + i32::from(D);
+        if shift.is_negative() {
+            return Err(TryFromFloatError::PrecisionLoss);
+        }
+
+        // Any non-zero value scaled past `10^80` cannot fit a `u128`.
+        let magnitude = U512::from(10)
+            .checked_pow(U512::from(min(shift, 80)))
+            .and_then(|factor| factor.checked_mul(U512::from(digits)))
+            .expect("`10^80 * u64::MAX` fits U512");
+
+        narrow_float(x.is_sign_negative(), magnitude)
     }
 }
 
@@ -40,12 +836,18 @@ where
 mod tests {
     use std::str::FromStr;
 
-    use proptest::prelude::Arbitrary;
+    use malachite::base::num::arithmetic::traits::{Abs, Pow};
+    use malachite::base::num::conversion::traits::{FromSciString, IsInteger, RoundingFrom};
+    use malachite::base::rounding_modes::RoundingMode as MalachiteRounding;
+    use malachite::{Integer, Rational};
+    use proptest::prelude::{Arbitrary, Strategy, any, prop_oneof};
     use proptest::proptest;
     use proptest::test_runner::TestRunner;
 
     use super::*;
     use crate::macros::generate_tests_for_common_variants;
+    use crate::rounding::reference_round;
+    use crate::{Int128_18, Uint128_18};
 
     #[test]
     fn uint128_18_from_uint64_9() {
@@ -83,6 +885,145 @@ mod tests {
             .unwrap();
     }
 
+    #[test]
+    fn rescale_narrow() {
+        let d = "-1.234565".parse::<Decimal<i64, 6>>().unwrap();
+
+        assert_eq!(d.rescale::<5>(RoundingMode::HalfEven), Ok(Decimal(-123_456)));
+        assert_eq!(d.rescale::<5>(RoundingMode::HalfUp), Ok(Decimal(-123_457)));
+        assert_eq!(d.rescale::<2>(RoundingMode::Ceiling), Ok(Decimal(-123)));
+        assert_eq!(d.rescale::<0>(RoundingMode::Floor), Ok(Decimal(-2)));
+        assert_eq!(
+            d.try_rescale::<5>(),
+            Err(DecimalError::PrecisionLoss { op: Operation::Rescale, value: -1_234_565 }),
+        );
+        assert_eq!(Decimal::<i64, 6>(-1_230_000).try_rescale::<2>(), Ok(Decimal(-123)));
+    }
+
+    #[test]
+    fn rescale_widen() {
+        let d = Decimal::<i64, 6>(-1_234_565);
+
+        assert_eq!(d.rescale::<9>(RoundingMode::HalfEven), Ok(Decimal(-1_234_565_000)));
+        assert_eq!(d.try_rescale::<9>(), Ok(Decimal(-1_234_565_000)));
+        assert_eq!(d.rescale_exact::<9>(), Decimal(-1_234_565_000));
+        assert_eq!(d.try_rescale::<6>(), Ok(d));
+
+        assert_eq!(
+            Decimal::<i8, 0>::MAX.try_rescale::<1>(),
+            Err(DecimalError::Overflow { op: Operation::Rescale, lhs: 127, rhs: 10 }),
+        );
+        assert_eq!(Decimal::<i8, 0>::MAX.rescale_exact::<1>(), Decimal(127i8.wrapping_mul(10)));
+    }
+
+    generate_tests_for_common_variants!(rescale_round_trip);
+
+    fn rescale_round_trip<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + ScaledInteger<0> + Arbitrary,
+    {
+        proptest!(|(a: Decimal<I, D>, mode: RoundingMode)| {
+            let narrowed = a.rescale::<0>(mode).unwrap();
+            let truncated = a.rescale::<0>(RoundingMode::TowardZero).unwrap();
+            assert!(narrowed.0 == truncated.0 || narrowed.0 == truncated.0 + I::ONE
+                || narrowed.0 == truncated.0 - I::ONE);
+
+            match a.try_rescale::<0>() {
+                Ok(exact) => {
+                    assert_eq!(exact, narrowed);
+                    assert_eq!(exact.try_rescale::<D>(), Ok(a));
+                    assert_eq!(exact.rescale_exact::<D>(), a);
+                }
+                Err(err) => {
+                    assert_eq!(err, DecimalError::PrecisionLoss { op: Operation::Rescale, value: a.0 });
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn try_from_signed_to_unsigned() {
+        let value = "12.5".parse::<Decimal<i64, 9>>().unwrap();
+
+        assert_eq!(Decimal::<u64, 9>::try_from(value), Ok(Decimal(12_500_000_000)));
+        assert_eq!(Decimal::<u64, 9>::try_from(-value), Err(TryFromDecimalError::Overflow));
+        assert_eq!(
+            Decimal::<i64, 9>::try_from(Decimal::<u64, 9>::MAX),
+            Err(TryFromDecimalError::Overflow),
+        );
+    }
+
+    #[test]
+    fn try_from_matches_reference() {
+        try_from_differential::<i64, 9, u64, 9>();
+        try_from_differential::<u64, 9, i64, 9>();
+        try_from_differential::<i128, 18, i64, 9>();
+        try_from_differential::<i128, 18, u64, 12>();
+        try_from_differential::<u128, 18, i128, 20>();
+        try_from_differential::<i64, 9, i8, 1>();
+        try_from_differential::<u8, 2, i8, 0>();
+        try_from_differential::<i32, 0, u16, 3>();
+
+        // Widening backings where the scales may overflow or lose precision.
+        try_from_differential::<i64, 9, i128, 6>();
+        try_from_differential::<u8, 0, u16, 3>();
+        try_from_differential::<u64, 0, i128, 19>();
+        try_from_differential::<i8, 2, i128, 0>();
+        try_from_differential::<u32, 9, u64, 19>();
+    }
+
+    fn try_from_differential<I1, const D1: u8, I2, const D2: u8>()
+    where
+        I1: ScaledInteger<D1> + Arbitrary,
+        I2: ScaledInteger<D2>,
+        Decimal<I2, D2>: TryFrom<Decimal<I1, D1>, Error = TryFromDecimalError>,
+        Rational: From<Decimal<I1, D1>> + From<Decimal<I2, D2>> + From<I2>,
+    {
+        proptest!(|(value: Decimal<I1, D1>)| {
+            let reference = Rational::from(value);
+
+            match Decimal::<I2, D2>::try_from(value) {
+                Ok(out) => assert_eq!(Rational::from(out), reference),
+                Err(TryFromDecimalError::PrecisionLoss) => {
+                    let scaled = &reference * Rational::from(I2::SCALING_FACTOR);
+                    assert!(!scaled.is_integer());
+                }
+                Err(TryFromDecimalError::Overflow) => {
+                    assert!(
+                        reference < Rational::from(Decimal::<I2, D2>::MIN)
+                            || reference > Rational::from(Decimal::<I2, D2>::MAX)
+                    );
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn from_matches_reference() {
+        from_differential::<u8, 0, u16, 2>();
+        from_differential::<i8, 1, i16, 3>();
+        from_differential::<u32, 5, i64, 9>();
+        from_differential::<i64, 9, i128, 18>();
+        from_differential::<u64, 0, i128, 18>();
+        from_differential::<i64, 9, i128, 9>();
+        from_differential::<u8, 2, u128, 38>();
+        from_differential::<u64, 19, u128, 38>();
+        from_differential::<i16, 4, i128, 37>();
+        from_differential::<u32, 0, i64, 9>();
+    }
+
+    fn from_differential<I1, const D1: u8, I2, const D2: u8>()
+    where
+        I1: ScaledInteger<D1> + Arbitrary,
+        I2: ScaledInteger<D2>,
+        Decimal<I2, D2>: From<Decimal<I1, D1>>,
+        Rational: From<Decimal<I1, D1>> + From<Decimal<I2, D2>>,
+    {
+        proptest!(|(value: Decimal<I1, D1>)| {
+            assert_eq!(Rational::from(Decimal::<I2, D2>::from(value)), Rational::from(value));
+        });
+    }
+
     generate_tests_for_common_variants!(to_f64_does_not_panic);
 
     fn to_f64_does_not_panic<I, const D: u8>()
@@ -93,4 +1034,261 @@ mod tests {
             a.to_f64();
         });
     }
+
+    /// Generates a reference conversion that rounds `|exact|` between its float
+    /// neighbours, treating overflow like IEEE-754 (as if the exponent were
+    /// unbounded).
+    macro_rules! reference_float {
+        ($name:ident, $float:ty) => {
+            fn $name(exact: &Rational, mode: RoundingMode) -> $float {
+                let negative = *exact < 0u32;
+                let magnitude = exact.abs();
+                let down = <$float>::rounding_from(&magnitude, MalachiteRounding::Down).0;
+                let up = <$float>::rounding_from(&magnitude, MalachiteRounding::Up).0;
+                let out = match down == up {
+                    true => down,
+                    false => {
+                        let lower = Rational::try_from(down).unwrap();
+                        let upper = match up.is_finite() {
+                            true => Rational::try_from(up).unwrap(),
+                            false => {
+                                let previous = Rational::try_from(down.next_down()).unwrap();
+                                &lower + &lower - previous
+                            }
+                        };
+                        let half = (&magnitude - &lower).cmp(&(&upper - &magnitude));
+                        match mode.rounds_away(negative, down.to_bits() & 1 == 1, half) {
+                            true => up,
+                            false => down,
+                        }
+                    }
+                };
+
+                match negative {
+                    true => -out,
+                    false => out,
+                }
+            }
+        };
+    }
+
+    reference_float!(reference_f64, f64);
+    reference_float!(reference_f32, f32);
+
+    #[test]
+    fn to_float_edges() {
+        // `2^53 + 1` is a tie between two doubles that dividing as floats misses.
+        let tie = Decimal::<u128, 1>(90_071_992_547_409_930);
+        assert_eq!(tie.to_f64(), 9_007_199_254_740_992.0);
+        assert_eq!(tie.to_f64_round(RoundingMode::HalfUp), 9_007_199_254_740_994.0);
+        assert_eq!(
+            (Decimal::<u128, 18>::ONE / Decimal(3_000_000_000_000_000_000)).to_f64(),
+            1.0 / 3.0
+        );
+
+        // Only values past `f32::MAX` by at least half an ulp overflow.
+        let max = Decimal::<u128, 0>(f32::MAX as u128 + (1 << 103) - 1);
+        assert_eq!(max.to_f32(), f32::MAX);
+        assert_eq!(Decimal::<u128, 0>(max.0 + 1).to_f32(), f32::INFINITY);
+        assert_eq!(Decimal::<u128, 0>::MAX.to_f32_round(RoundingMode::TowardZero), f32::MAX);
+        assert_eq!(Decimal::<u128, 0>::MAX.to_f32_round(RoundingMode::Ceiling), f32::INFINITY);
+        assert_eq!(Decimal::<i128, 0>::MIN.to_f32_round(RoundingMode::Floor), -(2f32.powi(127)));
+
+        // `1e-38` is subnormal as an `f32`.
+        let tiny = Decimal::<u128, 38>(1);
+        let floor = tiny.to_f32_round(RoundingMode::Floor);
+        assert!(floor.is_subnormal());
+        assert_eq!(tiny.to_f32_round(RoundingMode::Ceiling), floor.next_up());
+        assert_eq!(tiny.to_f32(), 1e-38);
+        assert_eq!(
+            Decimal::<i8, 1>::ZERO
+                .to_f64_round(RoundingMode::Floor)
+                .to_bits(),
+            0
+        );
+    }
+
+    generate_tests_for_common_variants!(to_float_differential);
+
+    fn to_float_differential<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, mode: RoundingMode)| {
+            let exact = Rational::from(a);
+
+            assert_eq!(a.to_f64_round(mode).to_bits(), reference_f64(&exact, mode).to_bits());
+            assert_eq!(a.to_f32_round(mode).to_bits(), reference_f32(&exact, mode).to_bits());
+            let nearest = f64::rounding_from(&exact, MalachiteRounding::Nearest).0;
+            assert_eq!(a.to_f64().to_bits(), nearest.to_bits());
+        });
+    }
+
+    #[test]
+    fn parts_edges() {
+        let d = Decimal::<i64, 3>(-34);
+
+        assert_eq!(d.into_parts(), (true, 0, 34));
+        assert_eq!(Decimal::<i64, 3>::from_parts(true, 0, 34), Some(d));
+        assert_eq!(Decimal::<i64, 3>::from_parts(false, 0, 34), Some(-d));
+        assert_eq!(Decimal::<i64, 3>::from_parts(true, 0, 0), Some(Decimal::ZERO));
+        assert_eq!(Decimal::<i64, 3>::from_parts(false, 1, 1000), None);
+        assert_eq!(Decimal::<i64, 3>::from_parts(false, 1, -1), None);
+        assert_eq!(Decimal::<i64, 3>::from_parts(true, 1, 1), None);
+        assert_eq!(Decimal::<i64, 3>::from_parts(false, -1, 1), None);
+        assert_eq!(Decimal::<i8, 1>::MIN.into_parts(), (true, -12, 8));
+        assert_eq!(Decimal::<i8, 1>::from_parts(true, -12, 8), Some(Decimal::MIN));
+        assert_eq!(Decimal::<i8, 1>::from_parts(true, -12, 9), None);
+        assert_eq!(Decimal::<u8, 1>::MAX.into_parts(), (false, 25, 5));
+        assert_eq!(Decimal::<u8, 1>::from_parts(true, 0, 1), None);
+    }
+
+    #[test]
+    fn try_into_integer() {
+        let d = "-300".parse::<Decimal<i64, 9>>().unwrap();
+
+        assert_eq!(i16::try_from(d), Ok(-300));
+        assert_eq!(i8::try_from(d), Err(TryFromDecimalError::Overflow));
+        assert_eq!(u64::try_from(d), Err(TryFromDecimalError::Overflow));
+        assert_eq!(i64::try_from(Decimal::<i64, 9>(-1)), Err(TryFromDecimalError::PrecisionLoss));
+        assert_eq!(
+            usize::try_from(Decimal::<u128, 18>::MAX),
+            Err(TryFromDecimalError::PrecisionLoss)
+        );
+        assert_eq!(u128::try_from(Decimal::<u128, 0>::MAX), Ok(u128::MAX));
+    }
+
+    generate_tests_for_common_variants!(integer_differential);
+
+    fn integer_differential<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, mode: RoundingMode)| {
+            let exact = Rational::from(a);
+            assert_eq!(a.to_integer(mode).into(), reference_round(&exact, mode));
+
+            let (negative, integer, fractional) = a.into_parts();
+            assert!(I::ZERO <= fractional && fractional < I::SCALING_FACTOR);
+            assert_eq!(negative, a.0 < I::ZERO);
+            let sign = if negative && integer == I::ZERO { "-" } else { "" };
+            let decimals = D as usize;
+            assert_eq!(a.to_string(), format!("{sign}{integer}.{fractional:0>decimals$}"));
+            assert_eq!(Decimal::from_parts(negative, integer, fractional), Some(a));
+
+            let reference = exact.is_integer().then(|| Integer::try_from(&exact).unwrap());
+            match i64::try_from(a) {
+                Ok(out) => assert_eq!(reference, Some(Integer::from(out))),
+                Err(TryFromDecimalError::PrecisionLoss) => assert!(reference.is_none()),
+                Err(TryFromDecimalError::Overflow) => {
+                    assert!(i64::try_from(&reference.unwrap()).is_err());
+                }
+            }
+        });
+    }
+
+    #[test]
+    fn float_edges() {
+        type D = Decimal<i64, 9>;
+        type Wide = Decimal<u128, 0>;
+
+        assert_eq!(D::try_from(-0.0), Ok(D::ZERO));
+        assert_eq!(D::try_from(-1.5f32), Ok(Decimal(-1_500_000_000)));
+        assert_eq!(D::try_from(f64::MIN_POSITIVE), Err(TryFromFloatError::PrecisionLoss));
+        assert_eq!(D::try_from(f64::MAX), Err(TryFromFloatError::Overflow));
+        assert_eq!(D::try_from(f32::NEG_INFINITY), Err(TryFromFloatError::Infinite));
+        assert_eq!(D::from_f64_round(f64::NAN, RoundingMode::Floor), Err(TryFromFloatError::NaN));
+        assert_eq!(D::from_f64_shortest(-f64::NAN), Err(TryFromFloatError::NaN));
+
+        // The smallest subnormal rounds like any other value in `(0, 0.5)`.
+        let tiny = f64::from_bits(1);
+        assert_eq!(D::from_f64_round(tiny, RoundingMode::HalfUp), Ok(D::ZERO));
+        assert_eq!(D::from_f64_round(tiny, RoundingMode::Ceiling), Ok(Decimal(1)));
+        assert_eq!(D::from_f64_round(-tiny, RoundingMode::Floor), Ok(Decimal(-1)));
+        assert_eq!(D::from_f64_shortest(tiny), Err(TryFromFloatError::PrecisionLoss));
+
+        // `1e23` is really `99999999999999991611392`.
+        assert_eq!(Wide::try_from(1e23), Ok(Decimal(99_999_999_999_999_991_611_392)));
+        assert_eq!(Wide::from_f64_shortest(1e23), Ok(Decimal(10u128.pow(23))));
+        assert_eq!(Wide::from_f64_shortest(1e39), Err(TryFromFloatError::Overflow));
+        assert_eq!(Wide::from_f64_shortest(-1.0), Err(TryFromFloatError::Overflow));
+        assert_eq!(Wide::try_from(2f64.powi(128)), Err(TryFromFloatError::Overflow));
+        assert_eq!(
+            Wide::from_f64_round(2f64.powi(127), RoundingMode::HalfEven),
+            Ok(Decimal(1 << 127))
+        );
+
+        assert_eq!(D::from_f64_shortest(0.3), Ok(Decimal(300_000_000)));
+        assert_eq!(D::from_f64_shortest(-1.0 / 3.0), Err(TryFromFloatError::PrecisionLoss));
+        assert_eq!(D::from_f64_shortest(1e-9), Ok(Decimal(1)));
+        assert_eq!(D::from_f64_shortest(1.5e-9), Err(TryFromFloatError::PrecisionLoss));
+
+        // Trailing zeros of the shortest digits do not count as decimal places.
+        assert_eq!(Decimal::<i64, 0>::from_f64_shortest(1.0), Ok(Decimal(1)));
+        assert_eq!(Decimal::<i64, 0>::from_f64_shortest(120.0), Ok(Decimal(120)));
+        assert_eq!(Decimal::<i64, 0>::from_f64_shortest(1e16), Ok(Decimal(10i64.pow(16))));
+    }
+
+    generate_tests_for_common_variants!(float_differential);
+
+    fn float_differential<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        let floats = prop_oneof![
+            any::<f64>(),
+            -1e6..1e6f64,
+            any::<Decimal<I, D>>().prop_map(|a| a.to_f64()),
+        ];
+        let fits = |x: &Integer| I::min_value().into() <= *x && *x <= I::max_value().into();
+        proptest!(|(x in floats, mode: RoundingMode)| {
+            let Ok(exact) = Rational::try_from(x) else {
+                let err = if x.is_nan() { TryFromFloatError::NaN } else { TryFromFloatError::Infinite };
+                assert_eq!(Decimal::<I, D>::try_from(x), Err(err));
+                assert_eq!(Decimal::<I, D>::from_f64_round(x, mode), Err(err));
+                assert_eq!(Decimal::<I, D>::from_f64_shortest(x), Err(err));
+
+                return Ok(());
+            };
+            let scaled = exact * Rational::from_unsigneds(10u8, 1).pow(u64::from(D));
+
+            let reference = reference_round(&scaled, mode);
+            let out = Decimal::<I, D>::from_f64_round(x, mode);
+            assert_eq!(out.ok().map(|out| out.0.into()), fits(&reference).then_some(reference));
+
+            let out = Decimal::<I, D>::try_from(x);
+            match scaled.is_integer() {
+                true => {
+                    let reference = Integer::try_from(&scaled).unwrap();
+                    assert_eq!(out.ok().map(|out| out.0.into()), fits(&reference).then_some(reference));
+                }
+                false => assert_eq!(out, Err(TryFromFloatError::PrecisionLoss)),
+            }
+
+            // The shortest round-trip digits are exactly what `Display` prints.
+            let shortest = Rational::from_sci_string(&x.to_string()).unwrap()
+                * Rational::from_unsigneds(10u8, 1).pow(u64::from(D));
+            let out = Decimal::<I, D>::from_f64_shortest(x);
+            match shortest.is_integer() {
+                true => {
+                    let reference = Integer::try_from(&shortest).unwrap();
+                    assert_eq!(out.ok().map(|out| out.0.into()), fits(&reference).then_some(reference));
+                }
+                false => assert_eq!(out, Err(TryFromFloatError::PrecisionLoss)),
+            }
+        });
+    }
+
+    generate_tests_for_common_variants!(f32_matches_f64);
+
+    fn f32_matches_f64<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary,
+    {
+        proptest!(|(x: f32)| {
+            assert_eq!(Decimal::<I, D>::try_from(x), Decimal::try_from(f64::from(x)));
+        });
+    }
 }
@@ -1,9 +1,16 @@
+use std::borrow::Borrow;
 use std::cmp::Ordering;
+use std::iter::{Product, Sum};
 use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 use std::str::FromStr;
 
+use ruint::aliases::U256;
+
 use crate::display::ParseDecimalError;
+use crate::error::{DecimalError, Operation};
+use crate::full_mul_div::{WideningMul, WideningScale, from_sign_magnitude, sign_magnitude};
 use crate::integer::{ScaledInteger, SignedScaledInteger};
+use crate::rounding::{RoundingMode, round_div, round_div_uint};
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
@@ -61,6 +68,42 @@ where
     panic!("`Decimal` div out of range; lhs={lhs}; rhs={rhs}")
 }
 
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn mul_div_failed<I, const D: u8>(
+    lhs: Decimal<I, D>,
+    rhs: Decimal<I, D>,
+    div: Decimal<I, D>,
+    error: &DecimalError<I>,
+) -> !
+where
+    I: ScaledInteger<D>,
+{
+    match error {
+        DecimalError::TernaryDivisionByZero { .. } => {
+            panic!("`Decimal` mul_div division by zero; lhs={lhs}; rhs={rhs}; div={div}")
+        }
+        _ => panic!("`Decimal` mul_div out of range; lhs={lhs}; rhs={rhs}; div={div}"),
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn pow_failed<I, const D: u8, E>(value: Decimal<I, D>, exp: E, error: &DecimalError<I>) -> !
+where
+    I: ScaledInteger<D>,
+    E: std::fmt::Display,
+{
+    match error {
+        DecimalError::DivisionByZero { .. } => {
+            panic!("`Decimal` pow division by zero; value={value}; exp={exp}")
+        }
+        _ => panic!("`Decimal` pow overflowed; value={value}; exp={exp}"),
+    }
+}
+
 #[cold]
 #[inline(never)]
 #[track_caller]
@@ -87,11 +130,53 @@ where
 {
     pub const ZERO: Decimal<I, D> = Decimal(I::ZERO);
     pub const ONE: Decimal<I, D> = Decimal(I::SCALING_FACTOR);
+    /// Two, which fails to compile at scales where it does not fit the
+    /// backing despite [`Self::ONE`] fitting:
+    ///
+    /// ```rust,compile_fail
+    /// use const_decimal::Decimal;
+    ///
+    /// let _ = Decimal::<i128, 38>::TWO;
+    /// ```
     pub const TWO: Decimal<I, D> = Decimal(I::TWO_SCALING_FACTOR);
     pub const MIN: Decimal<I, D> = Decimal(I::MIN);
     pub const MAX: Decimal<I, D> = Decimal(I::MAX);
     pub const DECIMALS: u8 = D;
     pub const SCALING_FACTOR: I = I::SCALING_FACTOR;
+    /// The largest scale `D` supported by the backing integer `I`.
+    ///
+    /// Using a larger scale fails to compile, including through the `const fn`
+    /// API & [`dec!`](crate::dec):
+    ///
+    /// ```rust,compile_fail
+    /// use const_decimal::Decimal;
+    ///
+    /// let _ = Decimal::<u8, 3>::ONE;
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use const_decimal::{Decimal, dec};
+    ///
+    /// const A: Decimal<u8, 3> = dec!(0.001, u8, 3);
+    /// ```
+    ///
+    /// ```rust,compile_fail
+    /// use const_decimal::Decimal;
+    ///
+    /// let _ = Decimal::<u8, 3>(1).const_checked_add(Decimal(1));
+    /// ```
+    pub const MAX_SCALE: u8 = I::MAX_SCALE;
+    /// The number of integer digits that always fit alongside
+    /// [`Self::FRACTIONAL_DIGITS`] fractional digits, e.g. `9` for
+    /// `Decimal<i64, 9>` as `999999999.999999999` fits but not every 10 digit
+    /// integer does.
+    pub const INTEGER_DIGITS: u8 = {
+        let () = I::ASSERT_SCALE;
+
+        Self::MAX_SCALE - D
+    };
+    /// The number of fractional digits, i.e. the scale `D`.
+    pub const FRACTIONAL_DIGITS: u8 = D;
 
     #[deprecated(note = "use Self::MIN")]
     #[must_use]
@@ -152,7 +237,8 @@ where
     /// Round a number to a multiple of a given `quantum` toward zero.
     /// general ref: <https://en.wikipedia.org/wiki/Quantization_(signal_processing)>
     ///
-    /// By default, rust is rounding towards zero and so does this method.
+    /// By default, rust is rounding towards zero and so does this method. See
+    /// [`Self::quantize`] for other rounding modes.
     ///
     /// # Example:
     /// ```rust
@@ -173,239 +259,1441 @@ where
         #[allow(clippy::arithmetic_side_effects)]
         Self((self.0 / quantum.0) * quantum.0)
     }
-}
 
-impl<I, const D: u8> num_traits::Zero for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
+    /// Round a number to a multiple of a given positive `quantum` using
+    /// `mode`.
+    ///
+    /// Returns an error if `quantum` is not positive or the rounded value does
+    /// not fit the backing integer.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    /// // 11.65
+    /// let d = Decimal::<i64, 5>::try_from_scaled(1165, 2).unwrap();
+    /// // Allow only increments of 0.5
+    /// let tick = Decimal::<i64, 5>::try_from_scaled(5, 1).unwrap();
+    ///
+    /// // Asks round up & bids round down.
+    /// let ask = d.quantize(tick, RoundingMode::Ceiling).unwrap();
+    /// assert_eq!(ask, Decimal::try_from_scaled(120, 1).unwrap());
+    /// let bid = d.quantize(tick, RoundingMode::Floor).unwrap();
+    /// assert_eq!(bid, Decimal::try_from_scaled(115, 1).unwrap());
+    ///
+    /// // 11.65 is closer to 11.5 than 12.0.
+    /// let nearest = d.quantize(tick, RoundingMode::HalfUp).unwrap();
+    /// assert_eq!(nearest, Decimal::try_from_scaled(115, 1).unwrap());
+    /// ```
     #[inline]
-    fn zero() -> Self {
-        Self(I::zero())
+    pub fn quantize(self, quantum: Self, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        if quantum.0 <= I::ZERO {
+            return Err(DecimalError::InvalidQuantum { quantum: quantum.0 });
+        }
+
+        round_div(self.0, quantum.0, mode)
+            .checked_mul(&quantum.0)
+            .map(Decimal)
+            .ok_or(DecimalError::Overflow { op: Operation::Quantize, lhs: self.0, rhs: quantum.0 })
     }
 
+    /// Rounds to `places` decimal places using `mode`, keeping the type's
+    /// precision.
+    ///
+    /// Returns `self` unchanged if `places >= D`, and an error if rounding away
+    /// from zero does not fit the backing integer.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let d = "1.23456789".parse::<Decimal<i64, 9>>().unwrap();
+    /// assert_eq!(d.round_dp(2, RoundingMode::HalfEven).unwrap().to_string(), "1.230000000");
+    /// assert_eq!(d.round_dp(4, RoundingMode::HalfEven).unwrap().to_string(), "1.234600000");
+    /// ```
     #[inline]
-    fn is_zero(&self) -> bool {
-        self.0.is_zero()
+    pub fn round_dp(self, places: u8, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        let Some(quantum) = Self::dp_quantum(places) else {
+            return Ok(self);
+        };
+
+        round_div(self.0, quantum, mode)
+            .checked_mul(&quantum)
+            .map(Decimal)
+            .ok_or(DecimalError::Overflow { op: Operation::Round, lhs: self.0, rhs: quantum })
     }
-}
 
-impl<I, const D: u8> num_traits::One for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
+    /// Truncates to `places` decimal places (i.e. rounds toward zero), keeping
+    /// the type's precision.
     #[inline]
-    fn one() -> Self {
-        Self(I::SCALING_FACTOR)
+    #[must_use]
+    pub fn trunc_dp(self, places: u8) -> Self {
+        match Self::dp_quantum(places) {
+            // SAFETY: Truncating division followed by multiplication by the same
+            // non-zero quantum cannot overflow.
+            #[allow(clippy::arithmetic_side_effects)]
+            Some(quantum) => Decimal((self.0 / quantum) * quantum),
+            None => self,
+        }
     }
-}
-
-impl<I, const D: u8> num_traits::Num for Decimal<I, D>
-where
-    I: SignedScaledInteger<D>,
-{
-    type FromStrRadixErr = ParseDecimalError<I>;
 
-    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        if radix != 10 {
-            return Err(ParseDecimalError::RadixMustBe10);
+    /// Returns `true` if `self` has no non-zero digits beyond `places` decimal
+    /// places.
+    #[inline]
+    #[must_use]
+    pub fn is_rounded_to(self, places: u8) -> bool {
+        match Self::dp_quantum(places) {
+            // SAFETY: `quantum` is non-zero & positive so this cannot overflow.
+            #[allow(clippy::arithmetic_side_effects)]
+            Some(quantum) => self.0 % quantum == I::ZERO,
+            None => true,
         }
+    }
 
-        Self::from_str(str)
+    /// Rounds to the nearest integer toward negative infinity.
+    ///
+    /// Returns an error if the result does not fit the backing integer.
+    ///
+    /// # Example:
+    /// ```rust
+    /// use const_decimal::Decimal;
+    ///
+    /// let d = "-1.5".parse::<Decimal<i64, 9>>().unwrap();
+    /// assert_eq!(d.floor(), Ok("-2".parse().unwrap()));
+    /// assert_eq!(d.ceil(), Ok("-1".parse().unwrap()));
+    /// assert_eq!(d.trunc(), "-1".parse().unwrap());
+    /// assert_eq!(d.fract(), "-0.5".parse().unwrap());
+    /// ```
+    #[inline]
+    pub fn floor(self) -> Result<Self, DecimalError<I>> {
+        self.round_dp(0, RoundingMode::Floor)
     }
-}
 
-impl<I, const D: u8> num_traits::Signed for Decimal<I, D>
-where
-    I: SignedScaledInteger<D>,
-{
-    fn abs(&self) -> Self {
-        Self(self.0.abs())
+    /// Rounds to the nearest integer toward positive infinity.
+    ///
+    /// Returns an error if the result does not fit the backing integer.
+    #[inline]
+    pub fn ceil(self) -> Result<Self, DecimalError<I>> {
+        self.round_dp(0, RoundingMode::Ceiling)
     }
 
-    fn abs_sub(&self, other: &Self) -> Self {
-        Self(self.0.abs_sub(&other.0))
+    /// Rounds to an integer using `mode`.
+    ///
+    /// Returns an error if the result does not fit the backing integer.
+    #[inline]
+    pub fn round(self, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        self.round_dp(0, mode)
     }
 
-    fn signum(&self) -> Self {
-        Self(self.0.signum())
+    /// Discards the fractional part (i.e. rounds toward zero).
+    #[inline]
+    #[must_use]
+    pub fn trunc(self) -> Self {
+        self.trunc_dp(0)
     }
 
-    fn is_positive(&self) -> bool {
-        self.0.is_positive()
+    /// Returns the fractional part, which carries the sign of `self` such that
+    /// `self.trunc() + self.fract() == self`.
+    #[inline]
+    #[must_use]
+    pub fn fract(self) -> Self {
+        // SAFETY: `SCALING_FACTOR` cannot be zero & the remainder of a positive
+        // divisor cannot overflow.
+        #[allow(clippy::arithmetic_side_effects)]
+        Decimal(self.0 % I::SCALING_FACTOR)
     }
 
-    fn is_negative(&self) -> bool {
-        self.0.is_negative()
+    /// The raw quantum of the last digit kept when rounding to `places`, or
+    /// `None` if `places` retains every digit of `D`.
+    #[inline]
+    fn dp_quantum(places: u8) -> Option<I> {
+        // SAFETY: `places < D` so `10^(D - places)` divides the scaling factor
+        // exactly and neither operation can overflow.
+        #[allow(clippy::arithmetic_side_effects)]
+        (places < D).then(|| I::SCALING_FACTOR / I::TEN.pow(u32::from(places)))
     }
-}
 
-impl<I, const D: u8> Add for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    type Output = Self;
+    /// Adds `rhs` to `self`, returning an error on overflow.
+    #[inline]
+    pub fn checked_add(self, rhs: Self) -> Result<Self, DecimalError<I>> {
+        self.0
+            .checked_add(&rhs.0)
+            .map(Decimal)
+            .ok_or(DecimalError::Overflow { op: Operation::Add, lhs: self.0, rhs: rhs.0 })
+    }
 
+    /// Subtracts `rhs` from `self`, returning an error on overflow.
     #[inline]
-    #[track_caller]
-    fn add(self, rhs: Self) -> Self::Output {
-        match self.0.checked_add(&rhs.0) {
-            Some(out) => Decimal(out),
-            None => add_overflow(self, rhs),
-        }
+    pub fn checked_sub(self, rhs: Self) -> Result<Self, DecimalError<I>> {
+        self.0
+            .checked_sub(&rhs.0)
+            .map(Decimal)
+            .ok_or(DecimalError::Overflow { op: Operation::Sub, lhs: self.0, rhs: rhs.0 })
     }
-}
 
-impl<I, const D: u8> Sub for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    type Output = Self;
+    /// Multiplies `self` by `rhs`, returning an error if the result does not
+    /// fit the backing integer.
+    ///
+    /// The intermediate product is computed at full width so only the final
+    /// result needs to be in range.
+    #[inline]
+    pub fn checked_mul(self, rhs: Self) -> Result<Self, DecimalError<I>> {
+        I::try_full_mul_div(self.0, rhs.0, I::SCALING_FACTOR)
+            .map(Decimal)
+            .ok_or(DecimalError::OutOfRange { op: Operation::Mul, lhs: self.0, rhs: rhs.0 })
+    }
 
+    /// Divides `self` by `rhs`, returning an error if `rhs` is zero or the
+    /// result does not fit the backing integer.
     #[inline]
-    #[track_caller]
-    fn sub(self, rhs: Self) -> Self::Output {
-        match self.0.checked_sub(&rhs.0) {
-            Some(out) => Decimal(out),
-            None => sub_overflow(self, rhs),
+    pub fn checked_div(self, rhs: Self) -> Result<Self, DecimalError<I>> {
+        if rhs.0 == I::ZERO {
+            return Err(DecimalError::DivisionByZero {
+                op: Operation::Div,
+                lhs: self.0,
+                rhs: rhs.0,
+            });
         }
-    }
-}
 
-impl<I, const D: u8> Mul for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    type Output = Self;
+        I::try_full_mul_div(self.0, I::SCALING_FACTOR, rhs.0)
+            .map(Decimal)
+            .ok_or(DecimalError::OutOfRange { op: Operation::Div, lhs: self.0, rhs: rhs.0 })
+    }
 
+    /// Computes `self % rhs`, returning an error if `rhs` is zero or the
+    /// operation overflows (i.e. `MIN % -ε`).
     #[inline]
-    #[track_caller]
-    fn mul(self, rhs: Self) -> Self::Output {
-        match I::try_full_mul_div(self.0, rhs.0, I::SCALING_FACTOR) {
-            Some(out) => Decimal(out),
-            None => mul_out_of_range(self, rhs),
+    pub fn checked_rem(self, rhs: Self) -> Result<Self, DecimalError<I>> {
+        if rhs.0 == I::ZERO {
+            return Err(DecimalError::DivisionByZero {
+                op: Operation::Rem,
+                lhs: self.0,
+                rhs: rhs.0,
+            });
         }
+
+        self.0
+            .checked_rem(&rhs.0)
+            .map(Decimal)
+            .ok_or(DecimalError::Overflow { op: Operation::Rem, lhs: self.0, rhs: rhs.0 })
     }
-}
 
-impl<I, const D: u8> Div for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    type Output = Self;
+    /// Sums every item, returning the first overflow as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, DecimalError, Operation};
+    ///
+    /// let fees = [Decimal::<u8, 1>(100), Decimal(100), Decimal(100)];
+    /// assert_eq!(Decimal::checked_sum(&fees[..2]), Ok(Decimal(200)));
+    /// assert_eq!(
+    ///     Decimal::checked_sum(fees),
+    ///     Err(DecimalError::Overflow { op: Operation::Add, lhs: 200, rhs: 100 }),
+    /// );
+    /// ```
+    pub fn checked_sum<It>(iter: It) -> Result<Self, DecimalError<I>>
+    where
+        It: IntoIterator,
+        It::Item: Borrow<Self>,
+    {
+        iter.into_iter()
+            .try_fold(Self::ZERO, |acc, item| acc.checked_add(*item.borrow()))
+    }
+
+    /// Multiplies every item, returning the first overflow as an error.
+    pub fn checked_product<It>(iter: It) -> Result<Self, DecimalError<I>>
+    where
+        It: IntoIterator,
+        It::Item: Borrow<Self>,
+    {
+        iter.into_iter()
+            .try_fold(Self::ONE, |acc, item| acc.checked_mul(*item.borrow()))
+    }
 
+    /// Computes the truncated integer quotient of `self / rhs` alongside the
+    /// exact remainder, returning an error if `rhs` is zero or the quotient
+    /// overflows (i.e. `MIN / -ε`).
     #[inline]
-    #[track_caller]
-    fn div(self, rhs: Self) -> Self::Output {
+    pub fn checked_div_rem(self, rhs: Self) -> Result<(I, Self), DecimalError<I>> {
         if rhs.0 == I::ZERO {
-            division_by_zero(self, rhs);
+            return Err(DecimalError::DivisionByZero {
+                op: Operation::Div,
+                lhs: self.0,
+                rhs: rhs.0,
+            });
         }
 
-        match I::try_full_mul_div(self.0, I::SCALING_FACTOR, rhs.0) {
-            Some(out) => Decimal(out),
-            None => div_out_of_range(self, rhs),
-        }
+        // Both operands share the same scaling factor, so the integer quotient of
+        // the backing integers is the integer quotient of the decimals.
+        let quotient = self.0.checked_div(&rhs.0).ok_or(DecimalError::Overflow {
+            op: Operation::Div,
+            lhs: self.0,
+            rhs: rhs.0,
+        })?;
+
+        // SAFETY: `rhs` is non-zero and the quotient did not overflow.
+        #[allow(clippy::arithmetic_side_effects)]
+        Ok((quotient, Decimal(self.0 % rhs.0)))
     }
-}
 
-impl<I, const D: u8> std::ops::Rem for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    type Output = Self;
+    /// Computes the truncated integer quotient of `self / rhs` alongside the
+    /// exact remainder, such that `self == rhs * quotient + remainder`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::Decimal;
+    ///
+    /// // Split a balance into whole lots of 0.25 plus leftover dust.
+    /// let balance = "10.3".parse::<Decimal<u64, 6>>().unwrap();
+    /// let lot = "0.25".parse::<Decimal<u64, 6>>().unwrap();
+    ///
+    /// assert_eq!(balance.div_rem(lot), (41, "0.05".parse().unwrap()));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient overflows.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn div_rem(self, rhs: Self) -> (I, Self) {
+        match self.checked_div_rem(rhs) {
+            Ok(out) => out,
+            Err(DecimalError::DivisionByZero { .. }) => division_by_zero(self, rhs),
+            Err(_) => div_out_of_range(self, rhs),
+        }
+    }
 
+    /// Computes the integer quotient `q` of Euclidean division, such that
+    /// `self == rhs * q + self.rem_euclid(rhs)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient overflows.
     #[inline]
+    #[must_use]
     #[track_caller]
-    fn rem(self, rhs: Self) -> Self::Output {
-        match self.0.checked_rem(&rhs.0) {
-            Some(out) => Self(out),
-            None => rem_failed(self, rhs),
+    pub fn div_euclid(self, rhs: Self) -> I {
+        let (quotient, remainder) = self.div_rem(rhs);
+
+        // SAFETY: A non-zero remainder implies `|rhs| > ε`, therefore
+        // `|quotient| <= MAX / 2` and can be moved by one.
+        #[allow(clippy::arithmetic_side_effects)]
+        match (remainder.0 < I::ZERO, rhs.0 < I::ZERO) {
+            (false, _) => quotient,
+            (true, false) => quotient - I::ONE,
+            (true, true) => quotient + I::ONE,
         }
     }
-}
 
-impl<I, const D: u8> Neg for Decimal<I, D>
-where
-    I: SignedScaledInteger<D>,
-{
-    type Output = Self;
+    /// Computes the least non-negative remainder of `self / rhs`, which is
+    /// always in `[0, |rhs|)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient overflows.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        let (_, remainder) = self.div_rem(rhs);
+
+        // SAFETY: A negative remainder lies in `(-|rhs|, 0)`, so adding `|rhs|`
+        // (i.e. subtracting a negative `rhs`) cannot overflow.
+        #[allow(clippy::arithmetic_side_effects)]
+        match (remainder.0 < I::ZERO, rhs.0 < I::ZERO) {
+            (false, _) => remainder,
+            (true, false) => Decimal(remainder.0 + rhs.0),
+            (true, true) => Decimal(remainder.0 - rhs.0),
+        }
+    }
 
+    /// Computes the integer quotient of `self / rhs` rounded towards negative
+    /// infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient overflows.
     #[inline]
+    #[must_use]
     #[track_caller]
-    fn neg(self) -> Self::Output {
-        match self.0.checked_neg() {
-            Some(out) => Decimal(out),
-            None => neg_overflow(self),
+    pub fn div_floor(self, rhs: Self) -> I {
+        let (quotient, remainder) = self.div_rem(rhs);
+
+        // SAFETY: See `Self::div_euclid`.
+        #[allow(clippy::arithmetic_side_effects)]
+        match remainder.0 != I::ZERO && (remainder.0 < I::ZERO) != (rhs.0 < I::ZERO) {
+            true => quotient - I::ONE,
+            false => quotient,
         }
     }
-}
 
-impl<I, const D: u8> AddAssign for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
+    /// Computes the integer quotient of `self / rhs` rounded towards positive
+    /// infinity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the quotient overflows.
     #[inline]
+    #[must_use]
     #[track_caller]
-    fn add_assign(&mut self, rhs: Self) {
-        *self = *self + rhs;
+    pub fn div_ceil(self, rhs: Self) -> I {
+        let (quotient, remainder) = self.div_rem(rhs);
+
+        // SAFETY: See `Self::div_euclid`.
+        #[allow(clippy::arithmetic_side_effects)]
+        match remainder.0 != I::ZERO && (remainder.0 < I::ZERO) == (rhs.0 < I::ZERO) {
+            true => quotient + I::ONE,
+            false => quotient,
+        }
+    }
+
+    /// Adds `rhs` to `self`, clamping to [`Self::MIN`] or [`Self::MAX`] on
+    /// overflow.
+    #[inline]
+    #[must_use]
+    pub fn saturating_add(self, rhs: Self) -> Self {
+        Decimal(self.0.saturating_add(rhs.0))
+    }
+
+    /// Subtracts `rhs` from `self`, clamping to [`Self::MIN`] or [`Self::MAX`]
+    /// on overflow.
+    #[inline]
+    #[must_use]
+    pub fn saturating_sub(self, rhs: Self) -> Self {
+        Decimal(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Multiplies `self` by `rhs`, clamping to the bound matching the sign of
+    /// the true result when it does not fit.
+    #[inline]
+    #[must_use]
+    pub fn saturating_mul(self, rhs: Self) -> Self {
+        Decimal(I::saturating_full_mul_div(self.0, rhs.0, I::SCALING_FACTOR))
+    }
+
+    /// Divides `self` by `rhs`, clamping to the bound matching the sign of the
+    /// true result when it does not fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn saturating_div(self, rhs: Self) -> Self {
+        if rhs.0 == I::ZERO {
+            division_by_zero(self, rhs);
+        }
+
+        Decimal(I::saturating_full_mul_div(self.0, I::SCALING_FACTOR, rhs.0))
+    }
+
+    /// Adds `rhs` to `self`, wrapping around the bounds of the backing integer.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Decimal(self.0.wrapping_add(&rhs.0))
+    }
+
+    /// Subtracts `rhs` from `self`, wrapping around the bounds of the backing
+    /// integer.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Decimal(self.0.wrapping_sub(&rhs.0))
+    }
+
+    /// Multiplies `self` by `rhs`, wrapping the full width result around the
+    /// bounds of the backing integer.
+    #[inline]
+    #[must_use]
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        self.overflowing_mul(rhs).0
+    }
+
+    /// Divides `self` by `rhs`, wrapping the full width result around the
+    /// bounds of the backing integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        self.overflowing_div(rhs).0
+    }
+
+    /// Adds `rhs` to `self`, returning the wrapped result and whether an
+    /// overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        let (out, overflow) = self.0.overflowing_add(&rhs.0);
+
+        (Decimal(out), overflow)
+    }
+
+    /// Subtracts `rhs` from `self`, returning the wrapped result and whether
+    /// an overflow occurred.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        let (out, overflow) = self.0.overflowing_sub(&rhs.0);
+
+        (Decimal(out), overflow)
+    }
+
+    /// Multiplies `self` by `rhs`, returning the wrapped result and whether
+    /// the true result did not fit.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        let (out, overflow) = I::overflowing_full_mul_div(self.0, rhs.0, I::SCALING_FACTOR);
+
+        (Decimal(out), overflow)
+    }
+
+    /// Divides `self` by `rhs`, returning the wrapped result and whether the
+    /// true result did not fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        if rhs.0 == I::ZERO {
+            division_by_zero(self, rhs);
+        }
+
+        let (out, overflow) = I::overflowing_full_mul_div(self.0, I::SCALING_FACTOR, rhs.0);
+
+        (Decimal(out), overflow)
+    }
+
+    /// Multiplies `self` by `rhs`, rounding the full width product once
+    /// according to `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let a = Decimal::<i64, 2>::try_from_scaled(105, 2).unwrap();
+    /// let b = Decimal::<i64, 2>::try_from_scaled(5, 1).unwrap();
+    /// // 1.05 * 0.5 = 0.525
+    /// assert_eq!(a.mul_round(b, RoundingMode::TowardZero), Decimal(52));
+    /// assert_eq!(a.mul_round(b, RoundingMode::HalfEven), Decimal(52));
+    /// assert_eq!(a.mul_round(b, RoundingMode::HalfUp), Decimal(53));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rounded result does not fit the backing integer.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn mul_round(self, rhs: Self, mode: RoundingMode) -> Self {
+        match self.checked_mul_round(rhs, mode) {
+            Ok(out) => out,
+            Err(_) => mul_out_of_range(self, rhs),
+        }
+    }
+
+    /// Divides `self` by `rhs`, rounding the full width quotient once
+    /// according to `mode`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the rounded result does not fit the backing
+    /// integer.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn div_round(self, rhs: Self, mode: RoundingMode) -> Self {
+        match self.checked_div_round(rhs, mode) {
+            Ok(out) => out,
+            Err(DecimalError::DivisionByZero { .. }) => division_by_zero(self, rhs),
+            Err(_) => div_out_of_range(self, rhs),
+        }
+    }
+
+    /// Multiplies `self` by `rhs`, rounding according to `mode`, returning an
+    /// error if the rounded result does not fit the backing integer.
+    #[inline]
+    pub fn checked_mul_round(self, rhs: Self, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        I::try_full_mul_div_round(self.0, rhs.0, I::SCALING_FACTOR, mode)
+            .map(Decimal)
+            .ok_or(DecimalError::OutOfRange { op: Operation::Mul, lhs: self.0, rhs: rhs.0 })
+    }
+
+    /// Divides `self` by `rhs`, rounding according to `mode`, returning an
+    /// error if `rhs` is zero or the rounded result does not fit the backing
+    /// integer.
+    #[inline]
+    pub fn checked_div_round(self, rhs: Self, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        if rhs.0 == I::ZERO {
+            return Err(DecimalError::DivisionByZero {
+                op: Operation::Div,
+                lhs: self.0,
+                rhs: rhs.0,
+            });
+        }
+
+        I::try_full_mul_div_round(self.0, I::SCALING_FACTOR, rhs.0, mode)
+            .map(Decimal)
+            .ok_or(DecimalError::OutOfRange { op: Operation::Div, lhs: self.0, rhs: rhs.0 })
+    }
+
+    /// Computes `self * rhs / div`, keeping the product at full width and
+    /// rounding the quotient once according to `mode`.
+    ///
+    /// Unlike chaining `*` and `/`, this neither truncates twice nor overflows
+    /// on the intermediate product when the final result fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// // Pro-rata share of a 100 unit fill for an order of 7 out of 30.
+    /// let fill = "100".parse::<Decimal<u64, 6>>().unwrap();
+    /// let order = "7".parse::<Decimal<u64, 6>>().unwrap();
+    /// let total = "30".parse::<Decimal<u64, 6>>().unwrap();
+    ///
+    /// assert_eq!(fill.mul_div(order, total, RoundingMode::Floor), "23.333333".parse().unwrap());
+    /// assert_eq!(fill.mul_div(order, total, RoundingMode::Ceiling), "23.333334".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `div` is zero or the rounded result does not fit the backing
+    /// integer.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn mul_div(self, rhs: Self, div: Self, mode: RoundingMode) -> Self {
+        match self.checked_mul_div(rhs, div, mode) {
+            Ok(out) => out,
+            Err(err) => mul_div_failed(self, rhs, div, &err),
+        }
+    }
+
+    /// Computes `self * rhs / div` at full width, rounding once according to
+    /// `mode`.
+    ///
+    /// Returns an error if `div` is zero or the rounded result does not fit the
+    /// backing integer.
+    #[inline]
+    pub fn checked_mul_div(
+        self,
+        rhs: Self,
+        div: Self,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError<I>> {
+        if div.0 == I::ZERO {
+            return Err(DecimalError::TernaryDivisionByZero {
+                op: Operation::MulDiv,
+                lhs: self.0,
+                rhs: rhs.0,
+                div: div.0,
+            });
+        }
+
+        // The scaling factors cancel out: (a / s) * (b / s) / (c / s) = (a * b / c) / s.
+        I::try_full_mul_div_round(self.0, rhs.0, div.0, mode)
+            .map(Decimal)
+            .ok_or(DecimalError::TernaryOutOfRange {
+                op: Operation::MulDiv,
+                lhs: self.0,
+                rhs: rhs.0,
+                div: div.0,
+            })
+    }
+
+    /// Raises `self` to the integer power `exp`.
+    ///
+    /// Uses exponentiation by squaring where every multiplication is computed
+    /// at full width and rounded according to `mode`. Negative exponents take
+    /// the reciprocal of `self.powi(-exp)`, with the power rounded in the
+    /// opposite direction so that directed modes (e.g. [`RoundingMode::Floor`])
+    /// never land on the wrong side of the exact result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// // Compounding 5% over 10 periods.
+    /// let rate = "1.05".parse::<Decimal<u64, 9>>().unwrap();
+    /// assert_eq!(rate.powi(10, RoundingMode::HalfEven), "1.628894627".parse().unwrap());
+    /// assert_eq!(rate.powi(-1, RoundingMode::HalfEven), "0.952380952".parse().unwrap());
+    /// assert_eq!(rate.powi(-2, RoundingMode::Floor), "0.907029478".parse().unwrap());
+    /// assert_eq!(rate.powi(-2, RoundingMode::Ceiling), "0.907029479".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result (or an intermediate product) does not fit the
+    /// backing integer, or if `self` is zero and `exp` is negative.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn powi(self, exp: i32, mode: RoundingMode) -> Self {
+        match self.checked_powi(exp, mode) {
+            Ok(out) => out,
+            Err(err) => pow_failed(self, exp, &err),
+        }
+    }
+
+    /// Raises `self` to the integer power `exp`, rounding each multiplication
+    /// according to `mode`.
+    ///
+    /// Returns an error if the result (or an intermediate product) does not
+    /// fit, or if `self` is zero and `exp` is negative.
+    pub fn checked_powi(self, exp: i32, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        if !exp.is_negative() {
+            return self.checked_powu(exp.unsigned_abs(), mode);
+        }
+        if self.is_zero() {
+            return Err(DecimalError::DivisionByZero {
+                op: Operation::Pow,
+                lhs: I::SCALING_FACTOR,
+                rhs: self.0,
+            });
+        }
+
+        let overflow = DecimalError::UnaryOverflow { op: Operation::Pow, value: self.0 };
+        let (base_negative, magnitude) = sign_magnitude(self.0);
+        let (_, scaling) = sign_magnitude(I::SCALING_FACTOR);
+        let negative = base_negative && exp % 2 != 0;
+
+        // NB: The reciprocal decreases as the power's magnitude grows, so the
+        // power is rounded the other way for the final rounding to bound the
+        // exact result from the side `mode` asks for.
+        let power =
+            powu_magnitude(magnitude, exp.unsigned_abs(), scaling, negative, mode.reversed())
+                .ok_or(overflow)?;
+        if power == 0 {
+            // A power that rounded to zero has an unrepresentably large
+            // reciprocal.
+            return Err(overflow);
+        }
+
+        let numer = U256::from(scaling)
+            .checked_mul(U256::from(scaling))
+            .expect("two u128 always fit U256");
+        u128::try_from(round_div_uint(numer, U256::from(power), negative, mode))
+            .ok()
+            .and_then(|out| from_sign_magnitude(negative, out))
+            .map(Decimal)
+            .ok_or(overflow)
+    }
+
+    /// Exponentiation by squaring for non-negative exponents.
+    fn checked_powu(self, exp: u32, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        let (base_negative, magnitude) = sign_magnitude(self.0);
+        let (_, scaling) = sign_magnitude(I::SCALING_FACTOR);
+        let negative = base_negative && !exp.is_multiple_of(2);
+
+        powu_magnitude(magnitude, exp, scaling, negative, mode)
+            .and_then(|out| from_sign_magnitude(negative, out))
+            .map(Decimal)
+            .ok_or(DecimalError::UnaryOverflow { op: Operation::Pow, value: self.0 })
+    }
+
+    /// Raises `self` to the integer power `exp`, clamping to [`Self::MIN`] or
+    /// [`Self::MAX`] (matching the sign of the true result) on overflow.
+    ///
+    /// `0` raised to a negative power saturates to [`Self::MAX`].
+    #[inline]
+    #[must_use]
+    pub fn saturating_powi(self, exp: i32, mode: RoundingMode) -> Self {
+        self.checked_powi(exp, mode)
+            .unwrap_or(match self.0 < I::ZERO && exp % 2 != 0 {
+                true => Self::MIN,
+                false => Self::MAX,
+            })
+    }
+
+    /// Multiplies `self` by `rhs` without any truncation, producing a decimal
+    /// with twice the width and twice the scale.
+    ///
+    /// `u128` & `i128` backings widen to [`U256`](ruint::aliases::U256) &
+    /// [`I256`](crate::I256) respectively, which support [`Display`] &
+    /// [`TryFrom`] back to a primitive backing.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let a = "1.000000001".parse::<Decimal<i64, 9>>().unwrap();
+    /// let b = "-2.5".parse::<Decimal<i64, 9>>().unwrap();
+    /// assert_eq!(a.widening_mul(b), "-2.5000000025".parse::<Decimal<i128, 18>>().unwrap());
+    ///
+    /// let price = Decimal::<i128, 18>::MAX;
+    /// let notional = price.widening_mul(price);
+    /// assert_eq!(
+    ///     notional.to_string(),
+    ///     "28948022309329048855892746252171976962977.213799489202546401021394546514198529",
+    /// );
+    /// assert_eq!(Decimal::<i128, 18>::try_from(price.widening_mul(Decimal::ONE)), Ok(price));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn widening_mul(self, rhs: Self) -> <I as WideningScale<D>>::Product
+    where
+        I: WideningScale<D>,
+    {
+        I::product(WideningMul::widening_mul(self.0, rhs.0))
+    }
+}
+
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: SignedScaledInteger<D>,
+{
+    /// Negates `self`, returning an error if the result overflows (i.e.
+    /// `-Decimal::MIN`).
+    #[inline]
+    pub fn checked_neg(self) -> Result<Self, DecimalError<I>> {
+        self.0
+            .checked_neg()
+            .map(Decimal)
+            .ok_or(DecimalError::UnaryOverflow { op: Operation::Neg, value: self.0 })
+    }
+
+    /// Computes the absolute value of `self`, returning an error if the result
+    /// overflows (i.e. `Decimal::MIN.abs()`).
+    #[inline]
+    pub fn checked_abs(self) -> Result<Self, DecimalError<I>> {
+        match self.0 < I::ZERO {
+            true => self
+                .checked_neg()
+                .map_err(|_| DecimalError::UnaryOverflow { op: Operation::Abs, value: self.0 }),
+            false => Ok(self),
+        }
+    }
+
+    /// Negates `self`, clamping `-Decimal::MIN` to [`Self::MAX`].
+    #[inline]
+    #[must_use]
+    pub fn saturating_neg(self) -> Self {
+        self.checked_neg().unwrap_or(Self::MAX)
+    }
+
+    /// Negates `self`, wrapping `-Decimal::MIN` to [`Self::MIN`].
+    #[inline]
+    #[must_use]
+    pub fn wrapping_neg(self) -> Self {
+        // Two's complement negation.
+        Decimal((!self.0).wrapping_add(&I::ONE))
+    }
+
+    /// Negates `self`, returning the wrapped result and whether an overflow
+    /// occurred.
+    #[inline]
+    #[must_use]
+    pub fn overflowing_neg(self) -> (Self, bool) {
+        (self.wrapping_neg(), self.0 == I::MIN)
+    }
+}
+
+impl<I, const D: u8> num_traits::Zero for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    #[inline]
+    fn zero() -> Self {
+        Self(I::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<I, const D: u8> num_traits::One for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    #[inline]
+    fn one() -> Self {
+        Self(I::SCALING_FACTOR)
+    }
+}
+
+impl<I, const D: u8> num_traits::Num for Decimal<I, D>
+where
+    I: SignedScaledInteger<D>,
+{
+    type FromStrRadixErr = ParseDecimalError<I>;
+
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        if radix != 10 {
+            return Err(ParseDecimalError::RadixMustBe10);
+        }
+
+        Self::from_str(str)
+    }
+}
+
+impl<I, const D: u8> num_traits::Signed for Decimal<I, D>
+where
+    I: SignedScaledInteger<D>,
+{
+    fn abs(&self) -> Self {
+        Self(self.0.abs())
+    }
+
+    fn abs_sub(&self, other: &Self) -> Self {
+        Self(self.0.abs_sub(&other.0))
+    }
+
+    fn signum(&self) -> Self {
+        Self(self.0.signum())
+    }
+
+    fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl<I, const D: u8> num_traits::Pow<i32> for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Output = Self;
+
+    /// Equivalent to [`Decimal::powi`] with [`RoundingMode::TowardZero`],
+    /// matching [`Mul`] and [`Div`].
+    #[inline]
+    #[track_caller]
+    fn pow(self, exp: i32) -> Self::Output {
+        self.powi(exp, RoundingMode::TowardZero)
+    }
+}
+
+impl<I, const D: u8> num_traits::Pow<u32> for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Output = Self;
+
+    /// Equivalent to [`Decimal::powi`] with [`RoundingMode::TowardZero`],
+    /// matching [`Mul`] and [`Div`].
+    #[inline]
+    #[track_caller]
+    fn pow(self, exp: u32) -> Self::Output {
+        match self.checked_powu(exp, RoundingMode::TowardZero) {
+            Ok(out) => out,
+            Err(err) => pow_failed(self, exp, &err),
+        }
+    }
+}
+
+impl<I, const D: u8> Add for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn add(self, rhs: Self) -> Self::Output {
+        match self.checked_add(rhs) {
+            Ok(out) => out,
+            Err(_) => add_overflow(self, rhs),
+        }
+    }
+}
+
+impl<I, const D: u8> Sub for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn sub(self, rhs: Self) -> Self::Output {
+        match self.checked_sub(rhs) {
+            Ok(out) => out,
+            Err(_) => sub_overflow(self, rhs),
+        }
+    }
+}
+
+impl<I, const D: u8> Mul for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn mul(self, rhs: Self) -> Self::Output {
+        match self.checked_mul(rhs) {
+            Ok(out) => out,
+            Err(_) => mul_out_of_range(self, rhs),
+        }
+    }
+}
+
+impl<I, const D: u8> Div for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn div(self, rhs: Self) -> Self::Output {
+        match self.checked_div(rhs) {
+            Ok(out) => out,
+            Err(DecimalError::DivisionByZero { .. }) => division_by_zero(self, rhs),
+            Err(_) => div_out_of_range(self, rhs),
+        }
+    }
+}
+
+impl<I, const D: u8> std::ops::Rem for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn rem(self, rhs: Self) -> Self::Output {
+        match self.checked_rem(rhs) {
+            Ok(out) => out,
+            Err(_) => rem_failed(self, rhs),
+        }
+    }
+}
+
+impl<I, const D: u8> Sum for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    fn sum<It: Iterator<Item = Self>>(iter: It) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+impl<'a, I, const D: u8> Sum<&'a Self> for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    fn sum<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<I, const D: u8> Product for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    fn product<It: Iterator<Item = Self>>(iter: It) -> Self {
+        iter.fold(Self::ONE, Mul::mul)
+    }
+}
+
+impl<'a, I, const D: u8> Product<&'a Self> for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    fn product<It: Iterator<Item = &'a Self>>(iter: It) -> Self {
+        iter.copied().product()
+    }
+}
+
+impl<I, const D: u8> Neg for Decimal<I, D>
+where
+    I: SignedScaledInteger<D>,
+{
+    type Output = Self;
+
+    #[inline]
+    #[track_caller]
+    fn neg(self) -> Self::Output {
+        match self.checked_neg() {
+            Ok(out) => out,
+            Err(_) => neg_overflow(self),
+        }
+    }
+}
+
+impl<I, const D: u8> AddAssign for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    #[inline]
+    #[track_caller]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<I, const D: u8> SubAssign for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    #[inline]
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<I, const D: u8> MulAssign for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    #[inline]
+    #[track_caller]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<I, const D: u8> DivAssign for Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    #[inline]
+    #[track_caller]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Raises a magnitude with the given scaling factor to the power `exp` by
+/// squaring, rounding every product for a result of the given sign.
+///
+/// Rounding the magnitudes for the sign of the final result keeps directed
+/// modes consistent even when intermediate powers of a negative base have the
+/// opposite sign.
+///
+/// Returns `None` if an intermediate magnitude does not fit a `u128`.
+fn powu_magnitude(
+    magnitude: u128,
+    exp: u32,
+    scaling: u128,
+    negative: bool,
+    mode: RoundingMode,
+) -> Option<u128> {
+    let mul = |lhs: u128, rhs: u128| {
+        let product = U256::from(lhs)
+            .checked_mul(U256::from(rhs))
+            .expect("two u128 always fit U256");
+        u128::try_from(round_div_uint(product, U256::from(scaling), negative, mode)).ok()
+    };
+
+    let mut remaining = exp;
+    let mut base = magnitude;
+    let mut out = scaling;
+    while remaining > 0 {
+        if remaining & 1 == 1 {
+            out = mul(out, base)?;
+        }
+
+        remaining >>= 1;
+        if remaining > 0 {
+            base = mul(base, base)?;
+        }
+    }
+
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::{Debug, Display};
+    use std::ops::Shr;
+
+    use malachite::base::num::arithmetic::traits::Pow;
+    use malachite::base::num::basic::traits::Zero;
+    use malachite::base::num::conversion::traits::{FromSciString, IsInteger};
+    use malachite::{Integer, Rational};
+    use paste::paste;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::TryFromDecimalError;
+    use crate::rounding::reference_round;
+
+    #[test]
+    #[should_panic(expected = "`Decimal` division by zero; lhs=1.0; rhs=0.0")]
+    fn division_by_zero_message_contains_both_operands() {
+        let _ = Decimal::<i8, 1>::ONE / Decimal::ZERO;
+    }
+
+    #[test]
+    #[should_panic(expected = "`Decimal` div out of range; lhs=12.7; rhs=0.1")]
+    fn division_out_of_range_message_is_cause_neutral() {
+        let _ = Decimal::<i8, 1>::MAX / Decimal(1);
+    }
+
+    #[test]
+    fn checked_add_overflow_reports_operands() {
+        assert_eq!(
+            Decimal::<i8, 1>::MAX.checked_add(Decimal(1)),
+            Err(DecimalError::Overflow { op: Operation::Add, lhs: 127, rhs: 1 }),
+        );
+        assert_eq!(
+            Decimal::<u8, 1>::ZERO.checked_sub(Decimal(1)),
+            Err(DecimalError::Overflow { op: Operation::Sub, lhs: 0, rhs: 1 }),
+        );
+    }
+
+    #[test]
+    fn checked_mul_div_out_of_range() {
+        assert_eq!(
+            Decimal::<i8, 1>::MAX.checked_mul(Decimal::TWO),
+            Err(DecimalError::OutOfRange { op: Operation::Mul, lhs: 127, rhs: 20 }),
+        );
+        assert_eq!(
+            Decimal::<i8, 1>::MAX.checked_div(Decimal(1)),
+            Err(DecimalError::OutOfRange { op: Operation::Div, lhs: 127, rhs: 1 }),
+        );
+    }
+
+    #[test]
+    fn checked_division_by_zero() {
+        assert_eq!(
+            Decimal::<i64, 9>::ONE.checked_div(Decimal::ZERO),
+            Err(DecimalError::DivisionByZero { op: Operation::Div, lhs: 1_000_000_000, rhs: 0 }),
+        );
+        assert_eq!(
+            Decimal::<u64, 9>::ONE.checked_rem(Decimal::ZERO),
+            Err(DecimalError::DivisionByZero { op: Operation::Rem, lhs: 1_000_000_000, rhs: 0 }),
+        );
+    }
+
+    #[test]
+    fn checked_rem_min_by_negative_epsilon() {
+        assert_eq!(
+            Decimal::<i8, 1>::MIN.checked_rem(Decimal(-1)),
+            Err(DecimalError::Overflow { op: Operation::Rem, lhs: -128, rhs: -1 }),
+        );
+    }
+
+    #[test]
+    fn checked_neg_abs() {
+        assert_eq!(Decimal::<i8, 1>::MAX.checked_neg(), Ok(Decimal(-127)));
+        assert_eq!(Decimal::<i8, 1>(-5).checked_abs(), Ok(Decimal(5)));
+        assert_eq!(Decimal::<i8, 1>(5).checked_abs(), Ok(Decimal(5)));
+        assert_eq!(
+            Decimal::<i8, 1>::MIN.checked_neg(),
+            Err(DecimalError::UnaryOverflow { op: Operation::Neg, value: -128 }),
+        );
+        assert_eq!(
+            Decimal::<i8, 1>::MIN.checked_abs(),
+            Err(DecimalError::UnaryOverflow { op: Operation::Abs, value: -128 }),
+        );
+    }
+
+    #[test]
+    fn decimal_error_display() {
+        let err = Decimal::<i64, 9>::ONE
+            .checked_div(Decimal::ZERO)
+            .unwrap_err();
+
+        assert_eq!(err.op(), Operation::Div);
+        assert_eq!(err.to_string(), "`Decimal` div division by zero; lhs=1000000000; rhs=0");
+    }
+
+    #[test]
+    fn saturating_neg() {
+        assert_eq!(Decimal::<i8, 1>::MIN.saturating_neg(), Decimal::MAX);
+        assert_eq!(Decimal::<i8, 1>::MAX.saturating_neg(), Decimal(-127));
+        assert_eq!(Decimal::<i128, 18>::MIN.saturating_neg(), Decimal::MAX);
+    }
+
+    #[test]
+    fn wrapping_overflowing_neg() {
+        assert_eq!(Decimal::<i8, 1>::MIN.wrapping_neg(), Decimal::MIN);
+        assert_eq!(Decimal::<i8, 1>::MIN.overflowing_neg(), (Decimal::MIN, true));
+        assert_eq!(Decimal::<i8, 1>(5).overflowing_neg(), (Decimal(-5), false));
+        assert_eq!(Decimal::<i8, 1>::ZERO.overflowing_neg(), (Decimal::ZERO, false));
+    }
+
+    #[test]
+    fn saturating_mul_div_picks_bound_from_sign() {
+        assert_eq!(Decimal::<i128, 18>::MAX.saturating_mul(-Decimal::TWO), Decimal::MIN);
+        assert_eq!(Decimal::<i128, 18>::MIN.saturating_mul(-Decimal::TWO), Decimal::MAX);
+        assert_eq!(Decimal::<i128, 18>::MIN.saturating_div(Decimal(-1)), Decimal::MAX);
+        assert_eq!(Decimal::<i64, 9>::MAX.saturating_div(Decimal(-1)), Decimal::MIN);
+        assert_eq!(Decimal::<u128, 18>::MAX.saturating_mul(Decimal::TWO), Decimal::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "`Decimal` division by zero; lhs=1.0; rhs=0.0")]
+    fn saturating_div_by_zero_panics() {
+        let _ = Decimal::<i8, 1>::ONE.saturating_div(Decimal::ZERO);
     }
-}
 
-impl<I, const D: u8> SubAssign for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    #[inline]
-    #[track_caller]
-    fn sub_assign(&mut self, rhs: Self) {
-        *self = *self - rhs;
+    #[test]
+    fn quantize_rejects_non_positive_quantum() {
+        let value = Decimal::<i64, 9>::ONE;
+
+        assert_eq!(
+            value.quantize(Decimal::ZERO, RoundingMode::HalfEven),
+            Err(DecimalError::InvalidQuantum { quantum: 0 }),
+        );
+        assert_eq!(
+            value.quantize(-Decimal::ONE, RoundingMode::HalfEven),
+            Err(DecimalError::InvalidQuantum { quantum: -1_000_000_000 }),
+        );
     }
-}
 
-impl<I, const D: u8> MulAssign for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    #[inline]
-    #[track_caller]
-    fn mul_assign(&mut self, rhs: Self) {
-        *self = *self * rhs;
+    #[test]
+    fn quantize_overflow_near_max() {
+        let quantum = Decimal::<i8, 1>(10);
+
+        assert_eq!(Decimal::<i8, 1>::MAX.quantize(quantum, RoundingMode::Floor), Ok(Decimal(120)));
+        assert_eq!(
+            Decimal::<i8, 1>::MAX.quantize(quantum, RoundingMode::Ceiling),
+            Err(DecimalError::Overflow { op: Operation::Quantize, lhs: 127, rhs: 10 }),
+        );
+        assert_eq!(
+            Decimal::<i8, 1>::MIN.quantize(quantum, RoundingMode::Ceiling),
+            Ok(Decimal(-120))
+        );
+        assert_eq!(
+            Decimal::<i8, 1>::MIN.quantize(quantum, RoundingMode::Floor),
+            Err(DecimalError::Overflow { op: Operation::Quantize, lhs: -128, rhs: 10 }),
+        );
     }
-}
 
-impl<I, const D: u8> DivAssign for Decimal<I, D>
-where
-    I: ScaledInteger<D>,
-{
-    #[inline]
-    #[track_caller]
-    fn div_assign(&mut self, rhs: Self) {
-        *self = *self / rhs;
+    #[test]
+    fn quantize_negative_values() {
+        let quantum = Decimal::<i64, 2>(50);
+        let value = Decimal::<i64, 2>(-1165);
+
+        assert_eq!(value.quantize(quantum, RoundingMode::Floor), Ok(Decimal(-1200)));
+        assert_eq!(value.quantize(quantum, RoundingMode::Ceiling), Ok(Decimal(-1150)));
+        assert_eq!(value.quantize(quantum, RoundingMode::TowardZero), Ok(Decimal(-1150)));
+        assert_eq!(value.quantize(quantum, RoundingMode::AwayFromZero), Ok(Decimal(-1200)));
+        assert_eq!(
+            Decimal::<i64, 2>(-1175).quantize(quantum, RoundingMode::HalfEven),
+            Ok(Decimal(-1200))
+        );
+        assert_eq!(
+            Decimal::<i64, 2>(-1125).quantize(quantum, RoundingMode::HalfEven),
+            Ok(Decimal(-1100))
+        );
+        assert_eq!(
+            Decimal::<i64, 2>(-1125).quantize(quantum, RoundingMode::HalfDown),
+            Ok(Decimal(-1100))
+        );
+        assert_eq!(
+            Decimal::<i64, 2>(-1125).quantize(quantum, RoundingMode::HalfUp),
+            Ok(Decimal(-1150))
+        );
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use std::fmt::Debug;
-    use std::ops::Shr;
+    #[test]
+    #[should_panic(expected = "`Decimal` add overflowed; lhs=20.0; rhs=10.0")]
+    fn sum_overflow_panics() {
+        let _ = [Decimal::<u8, 1>(100); 3].iter().sum::<Decimal<u8, 1>>();
+    }
 
-    use malachite::base::num::basic::traits::Zero;
-    use malachite::{Integer, Rational};
-    use paste::paste;
-    use proptest::prelude::*;
+    #[test]
+    fn checked_product_reports_first_error() {
+        let items = [Decimal::<u8, 1>(100), Decimal(100), Decimal::ZERO];
+
+        assert_eq!(Decimal::<u8, 1>::checked_product(&[] as &[Decimal<u8, 1>]), Ok(Decimal::ONE));
+        assert_eq!(
+            Decimal::checked_product(items),
+            Err(DecimalError::OutOfRange { op: Operation::Mul, lhs: 100, rhs: 100 }),
+        );
+    }
 
-    use super::*;
+    #[test]
+    fn integer_rounding_edges() {
+        let d = "-2.5".parse::<Decimal<i64, 9>>().unwrap();
+
+        assert_eq!(d.round(RoundingMode::HalfEven), Ok("-2".parse().unwrap()));
+        assert_eq!(d.round(RoundingMode::HalfUp), Ok("-3".parse().unwrap()));
+        assert_eq!(Decimal::<i8, 1>(-5).floor(), Ok(Decimal(-10)));
+        assert_eq!(Decimal::<i8, 1>(-5).trunc(), Decimal::ZERO);
+        assert_eq!(
+            Decimal::<i8, 1>::MIN.floor(),
+            Err(DecimalError::Overflow { op: Operation::Round, lhs: -128, rhs: 10 }),
+        );
+        assert_eq!(
+            Decimal::<u8, 1>::MAX.ceil(),
+            Err(DecimalError::Overflow { op: Operation::Round, lhs: 255, rhs: 10 }),
+        );
+    }
 
     #[test]
-    #[should_panic(expected = "`Decimal` division by zero; lhs=1.0; rhs=0.0")]
-    fn division_by_zero_message_contains_both_operands() {
-        let _ = Decimal::<i8, 1>::ONE / Decimal::ZERO;
+    fn round_dp_edges() {
+        // 12.7 & -12.8.
+        assert_eq!(Decimal::<i8, 1>::MAX.round_dp(0, RoundingMode::Floor), Ok(Decimal(120)));
+        assert_eq!(
+            Decimal::<i8, 1>::MAX.round_dp(0, RoundingMode::HalfUp),
+            Err(DecimalError::Overflow { op: Operation::Round, lhs: 127, rhs: 10 }),
+        );
+        assert_eq!(
+            Decimal::<i8, 1>::MIN.round_dp(0, RoundingMode::HalfEven),
+            Err(DecimalError::Overflow { op: Operation::Round, lhs: -128, rhs: 10 }),
+        );
+        assert_eq!(Decimal::<i8, 1>::MIN.round_dp(0, RoundingMode::Ceiling), Ok(Decimal(-120)));
+        assert_eq!(Decimal::<i8, 1>::MIN.trunc_dp(0), Decimal(-120));
+
+        // Rounding to at least `D` places is a no-op.
+        assert_eq!(Decimal::<i8, 1>::MIN.round_dp(1, RoundingMode::HalfEven), Ok(Decimal::MIN));
+        assert_eq!(
+            Decimal::<i8, 1>::MAX.round_dp(u8::MAX, RoundingMode::Ceiling),
+            Ok(Decimal::MAX)
+        );
+        assert_eq!(Decimal::<i8, 1>::MAX.trunc_dp(3), Decimal::MAX);
+        assert!(Decimal::<i8, 1>::MAX.is_rounded_to(1));
+        assert!(!Decimal::<i8, 1>::MAX.is_rounded_to(0));
     }
 
     #[test]
-    #[should_panic(expected = "`Decimal` div out of range; lhs=12.7; rhs=0.1")]
-    fn division_out_of_range_message_is_cause_neutral() {
-        let _ = Decimal::<i8, 1>::MAX / Decimal(1);
+    fn int64_9_round_dp_statement() {
+        let value: Decimal<i64, 9> = "-2.345650001".parse().unwrap();
+
+        assert_eq!(
+            value
+                .round_dp(2, RoundingMode::HalfEven)
+                .unwrap()
+                .to_string(),
+            "-2.350000000"
+        );
+        assert_eq!(
+            value
+                .round_dp(4, RoundingMode::HalfEven)
+                .unwrap()
+                .to_string(),
+            "-2.345700000"
+        );
+        assert_eq!(
+            value
+                .round_dp(4, RoundingMode::HalfDown)
+                .unwrap()
+                .to_string(),
+            "-2.345700000"
+        );
+        assert_eq!(value.trunc_dp(4).to_string(), "-2.345600000");
+        assert!(value.trunc_dp(4).is_rounded_to(4));
+        assert!(!value.is_rounded_to(8));
     }
 
     macro_rules! test_basic_ops {
@@ -729,6 +2017,56 @@ mod tests {
                     differential_fuzz_div_assign::<$underlying, $decimals>();
                 }
 
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _checked>]() {
+                    differential_fuzz_checked::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _saturating_overflowing>]() {
+                    differential_fuzz_saturating_overflowing::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _mul_div_round>]() {
+                    differential_fuzz_mul_div_round::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _mul_div>]() {
+                    differential_fuzz_mul_div::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _div_rem>]() {
+                    differential_fuzz_div_rem::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _powi>]() {
+                    differential_fuzz_powi::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _quantize>]() {
+                    differential_fuzz_quantize::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _round_dp>]() {
+                    differential_fuzz_round_dp::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _sum_product>]() {
+                    differential_fuzz_sum_product::<$underlying, $decimals>();
+                }
+
+                #[test]
+                fn [<differential_fuzz_ $underlying _ $decimals _integer_rounding>]() {
+                    differential_fuzz_integer_rounding::<$underlying, $decimals>();
+                }
+
                 #[test]
                 fn [<differential_fuzz_ $underlying _ $decimals _from_scaled>]() {
                     differential_fuzz_from_scaled::<$underlying, $decimals>();
@@ -737,6 +2075,136 @@ mod tests {
         };
     }
 
+    #[test]
+    fn div_rem_negative() {
+        let a = "-7.5".parse::<Decimal<i64, 9>>().unwrap();
+        let b = "2".parse::<Decimal<i64, 9>>().unwrap();
+        let remainder = "-1.5".parse::<Decimal<i64, 9>>().unwrap();
+
+        assert_eq!(a.div_rem(b), (-3, remainder));
+        assert_eq!(a.div_rem(-b), (3, remainder));
+        assert_eq!((a.div_euclid(b), a.rem_euclid(b)), (-4, "0.5".parse().unwrap()));
+        assert_eq!((a.div_euclid(-b), a.rem_euclid(-b)), (4, "0.5".parse().unwrap()));
+        assert_eq!((a.div_floor(b), a.div_ceil(b)), (-4, -3));
+        assert_eq!((a.div_floor(-b), a.div_ceil(-b)), (3, 4));
+    }
+
+    #[test]
+    fn checked_div_rem_overflow() {
+        let min = Decimal::<i64, 9>::MIN;
+        let epsilon = Decimal::<i64, 9>(-1);
+
+        assert_eq!(
+            min.checked_div_rem(epsilon),
+            Err(DecimalError::Overflow { op: Operation::Div, lhs: i64::MIN, rhs: -1 }),
+        );
+        assert_eq!(min.div_rem(Decimal(1)), (i64::MIN, Decimal::ZERO));
+    }
+
+    #[test]
+    fn powi_edges() {
+        let two = Decimal::<i64, 9>::TWO;
+
+        assert_eq!(two.powi(0, RoundingMode::HalfEven), Decimal::ONE);
+        assert_eq!(Decimal::<i64, 9>::ZERO.powi(0, RoundingMode::HalfEven), Decimal::ONE);
+        assert_eq!((-two).powi(3, RoundingMode::HalfEven), "-8".parse().unwrap());
+        assert_eq!(two.powi(-2, RoundingMode::HalfEven), "0.25".parse().unwrap());
+        assert_eq!(num_traits::Pow::pow(two, 10i32), "1024".parse().unwrap());
+        assert_eq!(num_traits::Pow::pow(two, 10u32), "1024".parse().unwrap());
+        assert_eq!(
+            Decimal::<i64, 9>::ZERO.checked_powi(-1, RoundingMode::HalfEven),
+            Err(DecimalError::DivisionByZero { op: Operation::Pow, lhs: 1_000_000_000, rhs: 0 }),
+        );
+        assert_eq!(
+            two.checked_powi(64, RoundingMode::HalfEven),
+            Err(DecimalError::UnaryOverflow { op: Operation::Pow, value: two.0 }),
+        );
+        assert_eq!(two.saturating_powi(64, RoundingMode::HalfEven), Decimal::MAX);
+        assert_eq!((-two).saturating_powi(63, RoundingMode::HalfEven), Decimal::MIN);
+        assert_eq!((-two).saturating_powi(64, RoundingMode::HalfEven), Decimal::MAX);
+    }
+
+    #[test]
+    fn powi_rounds_each_step_at_full_width() {
+        // Truncating `Mul` drifts from the exact 1.0001^365 = 1.0371724113...
+        let rate = "1.0001".parse::<Decimal<i64, 9>>().unwrap();
+        let looped = (0..365).fold(Decimal::ONE, |acc, _| acc * rate);
+
+        assert_eq!(looped, "1.037172237".parse().unwrap());
+        assert_eq!(rate.powi(365, RoundingMode::HalfEven), "1.037172402".parse().unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "`Decimal` pow division by zero; value=0.000000000; exp=-1")]
+    fn powi_zero_negative_exponent_panics() {
+        let _ = Decimal::<i64, 9>::ZERO.powi(-1, RoundingMode::HalfEven);
+    }
+
+    #[test]
+    fn mul_div_avoids_intermediate_overflow() {
+        let a = Decimal::<i64, 9>::MAX;
+        let b = "2".parse::<Decimal<i64, 9>>().unwrap();
+        let c = "4".parse::<Decimal<i64, 9>>().unwrap();
+
+        assert!(a.checked_mul(b).is_err());
+        assert_eq!(a.mul_div(b, c, RoundingMode::TowardZero), Decimal(i64::MAX / 2));
+        assert_eq!(a.mul_div(b, c, RoundingMode::HalfUp), Decimal(i64::MAX / 2 + 1));
+        assert_eq!(
+            a.checked_mul_div(c, b, RoundingMode::HalfUp),
+            Err(DecimalError::TernaryOutOfRange {
+                op: Operation::MulDiv,
+                lhs: a.0,
+                rhs: c.0,
+                div: b.0
+            }),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "`Decimal` mul_div division by zero; lhs=1.000000000; rhs=2.000000000; div=0.000000000"
+    )]
+    fn mul_div_by_zero_panics() {
+        let _ = Decimal::<i64, 9>::ONE.mul_div(Decimal::TWO, Decimal::ZERO, RoundingMode::HalfEven);
+    }
+
+    #[test]
+    fn widening_mul_is_exact() {
+        differential_fuzz_widening_mul::<u8, 1, 2>();
+        differential_fuzz_widening_mul::<i8, 1, 2>();
+        differential_fuzz_widening_mul::<u32, 4, 8>();
+        differential_fuzz_widening_mul::<i64, 9, 18>();
+        differential_fuzz_widening_mul::<u128, 18, 36>();
+        differential_fuzz_widening_mul::<i128, 18, 36>();
+        differential_fuzz_widening_mul::<i128, 38, 76>();
+    }
+
+    fn differential_fuzz_widening_mul<I, const D: u8, const D2: u8>()
+    where
+        I: ScaledInteger<D>
+            + WideningScale<D, Product = Decimal<<I as WideningMul>::Wide, D2>>
+            + Arbitrary,
+        Decimal<I::Wide, D2>: Display,
+        Decimal<I, D>: TryFrom<Decimal<I::Wide, D2>, Error = TryFromDecimalError>,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, b: Decimal<I, D>)| {
+            let out = a.widening_mul(b);
+            let exact = Rational::from(a) * Rational::from(b);
+
+            assert_eq!(Rational::from_sci_string(&out.to_string()).unwrap(), exact);
+            match Decimal::<I, D>::try_from(out) {
+                Ok(narrow) => assert_eq!(Rational::from(narrow), exact),
+                Err(TryFromDecimalError::Overflow) => {
+                    assert!(exact < Rational::from(Decimal::<I, D>::MIN) || exact > Rational::from(Decimal::<I, D>::MAX));
+                }
+                Err(TryFromDecimalError::PrecisionLoss) => {
+                    assert!(!(exact / Rational::from(Decimal::<I, D>(I::ONE))).is_integer());
+                }
+            }
+        });
+    }
+
     fn differential_fuzz_add<I, const D: u8>()
     where
         I: ScaledInteger<D> + Arbitrary + std::panic::RefUnwindSafe,
@@ -913,6 +2381,422 @@ mod tests {
         });
     }
 
+    /// The checked operations only fail when the exact result cannot be
+    /// represented.
+    fn differential_fuzz_checked<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer>,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, b: Decimal<I, D>)| {
+            let min = Rational::from(Decimal::<I, D>::MIN);
+            let max = Rational::from(Decimal::<I, D>::MAX);
+            let in_range = |reference: &Rational| &min <= reference && reference <= &max;
+
+            let reference = Rational::from(a) + Rational::from(b);
+            match a.checked_add(b) {
+                Ok(out) => assert_eq!(Rational::from(out), reference),
+                Err(err) => {
+                    assert_eq!(err.op(), Operation::Add);
+                    assert!(!in_range(&reference));
+                }
+            }
+
+            let reference = Rational::from(a) - Rational::from(b);
+            match a.checked_sub(b) {
+                Ok(out) => assert_eq!(Rational::from(out), reference),
+                Err(err) => {
+                    assert_eq!(err.op(), Operation::Sub);
+                    assert!(!in_range(&reference));
+                }
+            }
+
+            // Multiplication & division truncate, so compare against the truncated
+            // integer reference.
+            let in_range = |reference: &Integer| {
+                &I::min_value().into() <= reference && reference <= &I::max_value().into()
+            };
+            let scaling: Integer = I::SCALING_FACTOR.into();
+            let reference = a.0.into() * b.0.into() / &scaling;
+            match a.checked_mul(b) {
+                Ok(out) => assert_eq!(out.0.into(), reference),
+                Err(_) => assert!(!in_range(&reference)),
+            }
+
+            match b.is_zero() {
+                true => assert!(matches!(
+                    a.checked_div(b),
+                    Err(DecimalError::DivisionByZero { op: Operation::Div, .. })
+                )),
+                false => {
+                    let reference = a.0.into() * &scaling / b.0.into();
+                    match a.checked_div(b) {
+                        Ok(out) => assert_eq!(out.0.into(), reference),
+                        Err(_) => assert!(!in_range(&reference)),
+                    }
+                }
+            }
+        });
+    }
+
+    /// Saturating ops clamp towards the sign of the true result and overflowing
+    /// ops wrap it modulo `2^BITS`.
+    fn differential_fuzz_saturating_overflowing<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer>,
+    {
+        fn check<I, const D: u8>(
+            reference: &Integer,
+            checked: Result<Decimal<I, D>, DecimalError<I>>,
+            saturating: Decimal<I, D>,
+            overflowing: (Decimal<I, D>, bool),
+        ) where
+            I: ScaledInteger<D> + Debug + Into<Integer>,
+        {
+            let modulus = Integer::from(1) << u64::from(I::zero().count_zeros());
+            match checked {
+                Ok(out) => {
+                    assert_eq!(saturating, out);
+                    assert_eq!(overflowing, (out, false));
+                }
+                Err(_) => {
+                    let bound = match *reference < 0 {
+                        true => Decimal::MIN,
+                        false => Decimal::MAX,
+                    };
+                    assert_eq!(saturating, bound);
+                    assert!(overflowing.1);
+                    assert_eq!((reference - overflowing.0.0.into()) % modulus, 0);
+                }
+            }
+        }
+
+        proptest!(|(a: Decimal<I, D>, b: Decimal<I, D>)| {
+            let scaling: Integer = I::SCALING_FACTOR.into();
+
+            check(
+                &(a.0.into() + b.0.into()),
+                a.checked_add(b),
+                a.saturating_add(b),
+                a.overflowing_add(b),
+            );
+            assert_eq!(a.wrapping_add(b), a.overflowing_add(b).0);
+            check(
+                &(a.0.into() - b.0.into()),
+                a.checked_sub(b),
+                a.saturating_sub(b),
+                a.overflowing_sub(b),
+            );
+            assert_eq!(a.wrapping_sub(b), a.overflowing_sub(b).0);
+            check(
+                &(a.0.into() * b.0.into() / &scaling),
+                a.checked_mul(b),
+                a.saturating_mul(b),
+                a.overflowing_mul(b),
+            );
+            assert_eq!(a.wrapping_mul(b), a.overflowing_mul(b).0);
+
+            if !b.is_zero() {
+                check(
+                    &(a.0.into() * &scaling / b.0.into()),
+                    a.checked_div(b),
+                    a.saturating_div(b),
+                    a.overflowing_div(b),
+                );
+                assert_eq!(a.wrapping_div(b), a.overflowing_div(b).0);
+            }
+        });
+    }
+
+    fn differential_fuzz_mul_div_round<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, b: Decimal<I, D>, mode: RoundingMode)| {
+            let scaling = <Rational as From<Integer>>::from(I::SCALING_FACTOR.into());
+            let fits = |reference: &Integer| {
+                I::min_value().into() <= *reference && *reference <= I::max_value().into()
+            };
+
+            let reference = reference_round(&(Rational::from(a) * Rational::from(b) * &scaling), mode);
+            match a.checked_mul_round(b, mode) {
+                Ok(out) => assert_eq!(out.0.into(), reference),
+                Err(_) => assert!(!fits(&reference)),
+            }
+
+            if !b.is_zero() {
+                let reference =
+                    reference_round(&(Rational::from(a) / Rational::from(b) * &scaling), mode);
+                match a.checked_div_round(b, mode) {
+                    Ok(out) => assert_eq!(out.0.into(), reference),
+                    Err(_) => assert!(!fits(&reference)),
+                }
+            }
+
+            // Truncation matches the existing operators.
+            if let Ok(out) = a.checked_mul(b) {
+                assert_eq!(a.mul_round(b, RoundingMode::TowardZero), out);
+            }
+        });
+    }
+
+    fn differential_fuzz_mul_div<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, b: Decimal<I, D>, c: Decimal<I, D>, mode: RoundingMode)| {
+            if c.is_zero() {
+                assert!(matches!(
+                    a.checked_mul_div(b, c, mode),
+                    Err(DecimalError::TernaryDivisionByZero { op: Operation::MulDiv, div, .. }) if div == c.0
+                ));
+
+                return Ok(());
+            }
+
+            let scaling = <Rational as From<Integer>>::from(I::SCALING_FACTOR.into());
+            let exact = Rational::from(a) * Rational::from(b) / Rational::from(c) * &scaling;
+            let reference = reference_round(&exact, mode);
+            match a.checked_mul_div(b, c, mode) {
+                Ok(out) => assert_eq!(out.0.into(), reference),
+                Err(_) => assert!(
+                    reference < I::min_value().into() || reference > I::max_value().into()
+                ),
+            }
+        });
+    }
+
+    fn differential_fuzz_div_rem<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+        Rational: From<Decimal<I, D>>,
+    {
+        proptest!(|(a: Decimal<I, D>, b: Decimal<I, D>)| {
+            if b.is_zero() {
+                assert!(matches!(a.checked_div_rem(b), Err(DecimalError::DivisionByZero { .. })));
+
+                return Ok(());
+            }
+
+            let exact = Rational::from(a) / Rational::from(b);
+            let (a_i, b_i): (Integer, Integer) = (a.0.into(), b.0.into());
+            let fits = |x: &Integer| I::min_value().into() <= *x && *x <= I::max_value().into();
+
+            let truncated = reference_round(&exact, RoundingMode::TowardZero);
+            let Ok((quotient, remainder)) = a.checked_div_rem(b) else {
+                assert!(!fits(&truncated));
+
+                return Ok(());
+            };
+            assert_eq!(quotient.into(), truncated);
+            assert_eq!(remainder.0.into(), &a_i - &truncated * &b_i);
+
+            let floor = reference_round(&exact, RoundingMode::Floor);
+            let ceiling = reference_round(&exact, RoundingMode::Ceiling);
+            assert_eq!(a.div_floor(b).into(), floor);
+            assert_eq!(a.div_ceil(b).into(), ceiling);
+
+            // Euclidean division always leaves a remainder in `[0, |b|)`.
+            let euclid = match b.0 > I::ZERO {
+                true => floor,
+                false => ceiling,
+            };
+            let rem_euclid: Integer = a.rem_euclid(b).0.into();
+            assert_eq!(a.div_euclid(b).into(), euclid);
+            assert_eq!(rem_euclid, &a_i - &euclid * &b_i);
+            let b_abs = match b_i < Integer::ZERO {
+                true => -b_i,
+                false => b_i,
+            };
+            assert!(Integer::ZERO <= rem_euclid && rem_euclid < b_abs);
+        });
+    }
+
+    fn differential_fuzz_powi<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        proptest!(|(a: Decimal<I, D>, exp in -4i32..=8, mode: RoundingMode)| {
+            let scaling: Integer = I::SCALING_FACTOR.into();
+            let fits = |x: &Integer| I::min_value().into() <= *x && *x <= I::max_value().into();
+            let out = a.checked_powi(exp, mode);
+            if a.is_zero() && exp < 0 {
+                assert!(matches!(out, Err(DecimalError::DivisionByZero { .. })));
+
+                return Ok(());
+            }
+
+            // The exact power in raw units.
+            let base = Rational::from_integers(a.0.into(), scaling.clone());
+            let exact = base.pow(i64::from(exp)) * <Rational as From<Integer>>::from(scaling.clone());
+
+            // At most one product or reciprocal is rounded, so the result is
+            // exact.
+            if (-1..=2).contains(&exp) {
+                let reference = reference_round(&exact, mode);
+                match out {
+                    Ok(out) => assert_eq!(out.0.into(), reference),
+                    Err(_) => assert!(!fits(&reference)),
+                }
+
+                return Ok(());
+            }
+
+            // Powers of values within [-1, 1] cannot overflow & each rounding
+            // contributes at most one unit.
+            let raw: Integer = a.0.into();
+            if exp >= 0 && raw <= scaling && -raw <= scaling {
+                let Ok(out) = out else {
+                    panic!("power of {a} overflowed");
+                };
+                let error = <Rational as From<Integer>>::from(out.0.into()) - &exact;
+                assert!((-8..=8).contains(&error), "{a}^{exp} = {out}");
+            }
+
+            // Directed modes bound the exact result from their side.
+            let Ok(out) = out else {
+                return Ok(());
+            };
+            let out = <Rational as From<Integer>>::from(out.0.into());
+            let toward_zero = match exact >= 0 {
+                true => out <= exact,
+                false => out >= exact,
+            };
+            match mode {
+                RoundingMode::TowardZero => assert!(toward_zero),
+                RoundingMode::AwayFromZero => assert!(out == exact || !toward_zero),
+                RoundingMode::Floor => assert!(out <= exact),
+                RoundingMode::Ceiling => assert!(out >= exact),
+                RoundingMode::HalfUp | RoundingMode::HalfDown | RoundingMode::HalfEven => {}
+            }
+        });
+    }
+
+    fn differential_fuzz_quantize<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        proptest!(|(a: Decimal<I, D>, quantum: Decimal<I, D>, mode: RoundingMode)| {
+            if quantum.0 <= I::ZERO {
+                assert_eq!(
+                    a.quantize(quantum, mode),
+                    Err(DecimalError::InvalidQuantum { quantum: quantum.0 }),
+                );
+
+                return Ok(());
+            }
+
+            let quantum_i: Integer = quantum.0.into();
+            let exact = Rational::from_integers(a.0.into(), quantum_i.clone());
+            let reference = reference_round(&exact, mode) * quantum_i;
+            match a.quantize(quantum, mode) {
+                Ok(out) => assert_eq!(out.0.into(), reference),
+                Err(err) => {
+                    assert_eq!(
+                        err,
+                        DecimalError::Overflow { op: Operation::Quantize, lhs: a.0, rhs: quantum.0 }
+                    );
+                    assert!(reference < I::min_value().into() || reference > I::max_value().into());
+                }
+            }
+
+            if mode == RoundingMode::TowardZero {
+                assert_eq!(a.quantize(quantum, mode), Ok(a.quantize_round_to_zero(quantum)));
+            }
+        });
+    }
+
+    fn differential_fuzz_round_dp<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        proptest!(|(a: Decimal<I, D>, places in 0..=D, mode: RoundingMode)| {
+            let quantum: Integer = I::TEN.pow(u32::from(D - places)).into();
+            let exact = Rational::from_integers(a.0.into(), quantum.clone());
+            let reference = reference_round(&exact, mode) * &quantum;
+            match a.round_dp(places, mode) {
+                Ok(out) => {
+                    assert_eq!(out.0.into(), reference);
+                    assert!(out.is_rounded_to(places));
+                }
+                Err(_) => {
+                    assert!(reference < I::min_value().into() || reference > I::max_value().into());
+                }
+            }
+
+            let truncated = reference_round(&exact, RoundingMode::TowardZero) * &quantum;
+            assert_eq!(a.trunc_dp(places).0.into(), truncated);
+            assert_eq!(a.is_rounded_to(places), a.0.into() == truncated);
+        });
+    }
+
+    fn differential_fuzz_sum_product<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + 'static,
+        Rational: From<Decimal<I, D>>,
+    {
+        let items = proptest::collection::vec(any::<Decimal<I, D>>(), 0..8);
+        proptest!(|(items in items)| {
+            let fits = |x: &Rational| {
+                Rational::from(Decimal::<I, D>::MIN) <= *x && *x <= Rational::from(Decimal::<I, D>::MAX)
+            };
+
+            // Any prefix overflowing makes the checked sum fail.
+            let mut reference = Some(Rational::ZERO);
+            for item in &items {
+                reference = reference
+                    .map(|acc| acc + Rational::from(*item))
+                    .filter(fits);
+            }
+            let out = Decimal::checked_sum(&items);
+            assert_eq!(out.ok().map(Rational::from), reference);
+            if let Ok(out) = out {
+                assert_eq!(items.iter().sum::<Decimal<I, D>>(), out);
+                assert_eq!(items.into_iter().sum::<Decimal<I, D>>(), out);
+            }
+        });
+
+        let items = proptest::collection::vec(any::<Decimal<I, D>>(), 0..4);
+        proptest!(|(items in items)| {
+            let mut reference = Ok(Decimal::<I, D>::ONE);
+            for item in &items {
+                reference = reference.and_then(|acc| acc.checked_mul(*item));
+            }
+            let out = Decimal::checked_product(&items);
+            assert_eq!(out, reference);
+            if let Ok(out) = out {
+                assert_eq!(items.iter().product::<Decimal<I, D>>(), out);
+                assert_eq!(items.into_iter().product::<Decimal<I, D>>(), out);
+            }
+        });
+    }
+
+    fn differential_fuzz_integer_rounding<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        proptest!(|(a: Decimal<I, D>, mode: RoundingMode)| {
+            let scaling: Integer = I::SCALING_FACTOR.into();
+            let exact = Rational::from_integers(a.0.into(), scaling.clone());
+            let fits = |x: &Integer| I::min_value().into() <= *x && *x <= I::max_value().into();
+            let expected = |mode| {
+                let reference = reference_round(&exact, mode) * &scaling;
+
+                fits(&reference).then_some(reference)
+            };
+
+            assert_eq!(a.floor().ok().map(|out| out.0.into()), expected(RoundingMode::Floor));
+            assert_eq!(a.ceil().ok().map(|out| out.0.into()), expected(RoundingMode::Ceiling));
+            assert_eq!(a.round(mode).ok().map(|out| out.0.into()), expected(mode));
+
+            let truncated = reference_round(&exact, RoundingMode::TowardZero);
+            assert_eq!(a.trunc().0.into(), &truncated * &scaling);
+            assert_eq!(a.fract().0.into(), a.0.into() - truncated * &scaling);
+        });
+    }
+
     fn differential_fuzz_from_scaled<I, const D: u8>()
     where
         I: ScaledInteger<D> + Arbitrary + std::panic::RefUnwindSafe + Into<Integer> + TryInto<u64>,
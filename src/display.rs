@@ -3,39 +3,59 @@ use std::fmt::Display;
 use std::num::ParseIntError;
 use std::str::FromStr;
 
+use ruint::aliases::U256;
 use thiserror::Error;
 
-use crate::{Decimal, ScaledInteger};
+use crate::{Decimal, I256, ScaledInteger};
 
 impl<I, const D: u8> Display for Decimal<I, D>
 where
     I: ScaledInteger<D>,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        // `SCALING_FACTOR` cannot be zero.
-        #[allow(clippy::arithmetic_side_effects)]
-        let integer = self.0 / I::SCALING_FACTOR;
-        // `SCALING_FACTOR` cannot be zero.
-        #[allow(clippy::arithmetic_side_effects)]
-        let fractional = self.0 % I::SCALING_FACTOR;
-
-        let is_negative = self.0 < I::ZERO;
+        let (negative, integer, fractional) = self.into_parts();
+
         // The quotient carries the sign unless it is zero.
-        let sign = match is_negative && integer == I::ZERO {
+        let sign = match negative && integer == I::ZERO {
             true => "-",
             false => "",
         };
-        // Only negate the remainder, whose magnitude is less than the positive scaling
-        // factor and therefore always representable.
-        let fractional = match is_negative {
-            true => (!fractional).wrapping_add(&I::ONE),
-            false => fractional,
-        };
 
         write!(f, "{sign}{integer}.{fractional:0>decimals$}", decimals = D as usize)
     }
 }
 
+/// Formats the exact products of [`Decimal::widening_mul`] for 128-bit backings.
+impl<const D: u8> Display for Decimal<U256, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_wide::<D>(false, self.0, f)
+    }
+}
+
+/// Formats the exact products of [`Decimal::widening_mul`] for 128-bit backings.
+impl<const D: u8> Display for Decimal<I256, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_wide::<D>(self.0.is_negative(), self.0.unsigned_abs(), f)
+    }
+}
+
+fn fmt_wide<const D: u8>(
+    negative: bool,
+    magnitude: U256,
+    f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+    let scaling = U256::from(10)
+        .checked_pow(U256::from(D))
+        .expect("`widening_mul` produces a scale of at most 76");
+    let (integer, fractional) = magnitude.div_rem(scaling);
+    let sign = match negative {
+        true => "-",
+        false => "",
+    };
+
+    write!(f, "{sign}{integer}.{fractional:0>decimals$}", decimals = D as usize)
+}
+
 impl<I, const D: u8> FromStr for Decimal<I, D>
 where
     I: ScaledInteger<D>,
@@ -47,53 +67,66 @@ where
             return Err(ParseDecimalError::EmptyString);
         }
 
-        // Strip the sign (-0 would parse to 0 and break our output).
+        // Strip the sign, as e.g. `-0.5` has no sign left once the integer part is
+        // parsed.
         let unsigned_s = s.strip_prefix('-').unwrap_or(s);
+        let negative = unsigned_s.len() != s.len();
+        let signed = I::min_value() < I::ZERO;
+
+        // The number may not contain a decimal point, but we can still try and parse
+        // it as an integer.
+        let (integer_s, fractional_s) = match unsigned_s.split_once('.') {
+            Some((integer_s, fractional_s)) => (integer_s, Some(fractional_s)),
+            None => (unsigned_s, None),
+        };
 
-        // Parse the unsigned representation.
-        let Some((integer_s, fractional_s)) = unsigned_s.split_once('.') else {
-            // The number does not contain a decimal point, but we can still try and parse
-            // it as an integer.
-            let integer = I::from_str(s)?;
-
-            return Decimal::try_from_scaled(integer, 0)
-                .ok_or(ParseDecimalError::Overflow(integer, I::ZERO));
+        // Parse the integer part along with its sign so that it can reach `I::MIN`.
+        // Unsigned backings parse the magnitude, as `-0` is their only negative
+        // value.
+        let integer = match negative && signed {
+            true => I::from_str(&s[..=integer_s.len()])?,
+            false => I::from_str(integer_s)?,
+        };
+        let fractional = match fractional_s {
+            Some(fractional_s) => {
+                let fractional = I::from_str(fractional_s)?;
+                let fractional_s_len = fractional_s.len();
+                match fractional_s_len.cmp(&(D as usize)) {
+                    Ordering::Equal => fractional,
+                    Ordering::Less => {
+                        // `fractional_s_len` guaranteed to be less than D.
+                        #[allow(clippy::arithmetic_side_effects)]
+                        let shortfall = D as usize - fractional_s_len;
+
+                        // SAFETY: `fractional` has fewer than `D` digits & `D` is asserted
+                        // to be at most `I::MAX_SCALE`, so the product is below `10^D`.
+                        #[allow(clippy::arithmetic_side_effects)]
+                        let fractional = fractional * I::pow(I::TEN, shortfall as u32);
+                        fractional
+                    }
+                    Ordering::Greater => {
+                        return Err(ParseDecimalError::PrecisionLoss(fractional_s_len));
+                    }
+                }
+            }
+            None => I::ZERO,
         };
-        let integer = I::from_str(integer_s)?;
-        let fractional = I::from_str(fractional_s)?;
 
         let scaled_integer = integer
             .checked_mul(&I::SCALING_FACTOR)
             .ok_or(ParseDecimalError::Overflow(integer, fractional))?;
 
-        let fractional_s_len = fractional_s.len();
-        let fractional = match fractional_s_len.cmp(&(D as usize)) {
-            Ordering::Equal => fractional,
-            Ordering::Less => {
-                // `fractional_s_len` guaranteed to be less than D.
-                #[allow(clippy::arithmetic_side_effects)]
-                let shortfall = D as usize - fractional_s_len;
-
-                // TODO: Remove the `checked_mul` in favor of ensuring `D` cannot overflow.
-                fractional
-                    .checked_mul(&I::pow(I::TEN, shortfall.try_into().unwrap()))
-                    .unwrap()
-            }
-            Ordering::Greater => return Err(ParseDecimalError::PrecisionLoss(fractional_s.len())),
-        };
-        let unsigned = scaled_integer
-            .checked_add(&fractional)
-            .ok_or(ParseDecimalError::Overflow(integer, fractional))?;
-
-        // Use two's complement to convert to the signed representation.
-        Ok(match unsigned_s.len() == s.len() {
-            true => Decimal(unsigned),
-            false => {
-                debug_assert_eq!(unsigned_s.len().checked_add(1).unwrap(), s.len());
-
-                Decimal((!unsigned).wrapping_add(&I::ONE))
+        // Negative values subtract the fractional part so that no intermediate has to
+        // hold the magnitude of `I::MIN`.
+        match (negative, signed) {
+            (false, _) => scaled_integer.checked_add(&fractional),
+            (true, true) => scaled_integer.checked_sub(&fractional),
+            (true, false) => {
+                (scaled_integer == I::ZERO && fractional == I::ZERO).then_some(I::ZERO)
             }
-        })
+        }
+        .map(Decimal)
+        .ok_or(ParseDecimalError::Overflow(integer, fractional))
     }
 }
 
@@ -224,6 +257,27 @@ mod tests {
         assert_eq!("-0.0000000001".parse::<Int64_9>(), Err(ParseDecimalError::PrecisionLoss(10)));
     }
 
+    #[test]
+    fn from_str_min_and_negative_zero() {
+        // Matches `dec!` & `const_from_str` on inputs that only fit as negatives.
+        assert_eq!("-12.8".parse::<Decimal<i8, 1>>(), Ok(Decimal::MIN));
+        assert_eq!("-128".parse::<Decimal<i8, 0>>(), Ok(Decimal::MIN));
+        assert_eq!(Int64_9::MIN.to_string().parse::<Int64_9>(), Ok(Int64_9::MIN));
+        assert_eq!(
+            "-1.70141183460469231731687303715884105728".parse::<Decimal<i128, 38>>(),
+            Ok(Decimal::MIN)
+        );
+        assert_eq!("-12.9".parse::<Decimal<i8, 1>>(), Err(ParseDecimalError::Overflow(-12, 9)));
+        assert_eq!("12.8".parse::<Decimal<i8, 1>>(), Err(ParseDecimalError::Overflow(12, 8)));
+
+        // `-0` is zero for every backing, while other negatives overflow unsigned ones.
+        assert_eq!("-0".parse::<Decimal<u8, 0>>(), Ok(Decimal(0)));
+        assert_eq!("-0.00".parse::<Decimal<u8, 2>>(), Ok(Decimal(0)));
+        assert_eq!("-0".parse::<Int64_9>(), Ok(Int64_9::ZERO));
+        assert_eq!("-0.01".parse::<Decimal<u8, 2>>(), Err(ParseDecimalError::Overflow(0, 1)));
+        assert_eq!("-1".parse::<Decimal<u8, 0>>(), Err(ParseDecimalError::Overflow(1, 0)));
+    }
+
     // TODO: Round trip fuzz test does not cover strings with precision greater/less
     // than target precision.
 
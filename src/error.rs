@@ -0,0 +1,101 @@
+use std::fmt::Display;
+
+use thiserror::Error;
+
+/// The arithmetic operation that produced a [`DecimalError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Neg,
+    Abs,
+    Quantize,
+    Round,
+    Rescale,
+    MulDiv,
+    Sqrt,
+    Root,
+    Pow,
+    Exp,
+    Ln,
+    Log10,
+    Log2,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Operation::Add => "add",
+            Operation::Sub => "sub",
+            Operation::Mul => "mul",
+            Operation::Div => "div",
+            Operation::Rem => "rem",
+            Operation::Neg => "neg",
+            Operation::Abs => "abs",
+            Operation::Quantize => "quantize",
+            Operation::Round => "round",
+            Operation::Rescale => "rescale",
+            Operation::MulDiv => "mul_div",
+            Operation::Sqrt => "sqrt",
+            Operation::Root => "root",
+            Operation::Pow => "pow",
+            Operation::Exp => "exp",
+            Operation::Ln => "ln",
+            Operation::Log10 => "log10",
+            Operation::Log2 => "log2",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Error returned by the checked operations on [`Decimal`](crate::Decimal).
+///
+/// Operands are reported as the raw backing integers (i.e. `Decimal::0`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum DecimalError<I>
+where
+    I: Display,
+{
+    #[error("`Decimal` {op} overflowed; lhs={lhs}; rhs={rhs}")]
+    Overflow { op: Operation, lhs: I, rhs: I },
+    #[error("`Decimal` {op} overflowed; value={value}")]
+    UnaryOverflow { op: Operation, value: I },
+    #[error("`Decimal` {op} out of range; lhs={lhs}; rhs={rhs}")]
+    OutOfRange { op: Operation, lhs: I, rhs: I },
+    #[error("`Decimal` {op} division by zero; lhs={lhs}; rhs={rhs}")]
+    DivisionByZero { op: Operation, lhs: I, rhs: I },
+    #[error("`Decimal` {op} out of range; lhs={lhs}; rhs={rhs}; div={div}")]
+    TernaryOutOfRange { op: Operation, lhs: I, rhs: I, div: I },
+    #[error("`Decimal` {op} division by zero; lhs={lhs}; rhs={rhs}; div={div}")]
+    TernaryDivisionByZero { op: Operation, lhs: I, rhs: I, div: I },
+    #[error("`Decimal` quantum must be positive; quantum={quantum}")]
+    InvalidQuantum { quantum: I },
+    #[error("`Decimal` {op} would lose precision; value={value}")]
+    PrecisionLoss { op: Operation, value: I },
+    #[error("`Decimal` {op} is undefined; value={value}")]
+    Undefined { op: Operation, value: I },
+}
+
+impl<I> DecimalError<I>
+where
+    I: Display,
+{
+    /// The operation that failed.
+    pub const fn op(&self) -> Operation {
+        match self {
+            DecimalError::Overflow { op, .. }
+            | DecimalError::UnaryOverflow { op, .. }
+            | DecimalError::OutOfRange { op, .. }
+            | DecimalError::DivisionByZero { op, .. }
+            | DecimalError::TernaryOutOfRange { op, .. }
+            | DecimalError::TernaryDivisionByZero { op, .. }
+            | DecimalError::PrecisionLoss { op, .. }
+            | DecimalError::Undefined { op, .. } => *op,
+            DecimalError::InvalidQuantum { .. } => Operation::Quantize,
+        }
+    }
+}
@@ -2,8 +2,9 @@ use std::fmt::Debug;
 
 use proptest::arbitrary::Mapped;
 use proptest::prelude::{Arbitrary, Strategy, any};
+use proptest::sample::{Select, select};
 
-use crate::{Decimal, ScaledInteger};
+use crate::{Decimal, RoundingMode, ScaledInteger};
 
 impl<const D: u8, I> Arbitrary for Decimal<I, D>
 where
@@ -20,3 +21,24 @@ where
         any::<I>().prop_map(|integer| Decimal(integer))
     }
 }
+
+impl Arbitrary for RoundingMode {
+    type Parameters = ();
+    type Strategy = Select<Self>;
+
+    fn arbitrary() -> Self::Strategy {
+        Self::arbitrary_with(())
+    }
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        select(vec![
+            RoundingMode::TowardZero,
+            RoundingMode::AwayFromZero,
+            RoundingMode::Floor,
+            RoundingMode::Ceiling,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+        ])
+    }
+}
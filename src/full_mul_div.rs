@@ -1,7 +1,10 @@
 use std::fmt::Display;
 
-use ruint::Uint;
-use ruint::aliases::U256;
+use num_traits::PrimInt;
+use ruint::aliases::{U256, U512};
+
+use crate::rounding::{round_div, round_div_uint};
+use crate::{Decimal, I256, RoundingMode};
 
 #[cold]
 #[inline(never)]
@@ -38,6 +41,94 @@ pub trait FullMulDiv: Sized {
     /// `Self`.
     #[track_caller]
     fn try_full_mul_div(self, rhs: Self, div: Self) -> Option<Self>;
+
+    /// Implements `a * b / c` with full width on the intermediate `a * b`
+    /// state, rounding the quotient once according to `mode`.
+    ///
+    /// Returns `None` when `div` is zero or the rounded result does not fit
+    /// `Self`.
+    #[track_caller]
+    fn try_full_mul_div_round(self, rhs: Self, div: Self, mode: RoundingMode) -> Option<Self>;
+
+    /// Implements `a * b / c` with full width on the intermediate `a * b`
+    /// state, returning the result wrapped to `Self` along with a flag
+    /// indicating whether the result did not fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `div` is zero.
+    #[track_caller]
+    fn overflowing_full_mul_div(self, rhs: Self, div: Self) -> (Self, bool);
+
+    /// Implements `a * b / c` with full width on the intermediate `a * b`
+    /// state, clamping to `Self::MIN` or `Self::MAX` (matching the sign of the
+    /// true result) when the result does not fit.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `div` is zero.
+    #[track_caller]
+    fn saturating_full_mul_div(self, rhs: Self, div: Self) -> Self;
+}
+
+/// Maps an integer to its double-width partner.
+pub trait WideningMul: Sized {
+    /// Integer with twice the width of `Self`.
+    type Wide;
+
+    /// Computes `a * b` exactly in the double-width type.
+    fn widening_mul(self, rhs: Self) -> Self::Wide;
+}
+
+/// Maps a scale `D` of `Self` to the decimal that holds the exact product of
+/// two `Decimal<Self, D>`, i.e. `Decimal<Self::Wide, {2 * D}>` (see
+/// [`Decimal::widening_mul`]).
+pub trait WideningScale<const D: u8>: WideningMul {
+    type Product;
+
+    /// Wraps a double-width product of two raw values at twice the scale.
+    fn product(wide: Self::Wide) -> Self::Product;
+}
+
+macro_rules! impl_widening_scale {
+    ($primary:ty; $($scale:literal)+) => {
+        $(
+            impl WideningScale<$scale> for $primary {
+                type Product = Decimal<<$primary as WideningMul>::Wide, { 2 * $scale }>;
+
+                #[inline]
+                fn product(wide: Self::Wide) -> Self::Product {
+                    Decimal(wide)
+                }
+            }
+        )+
+    };
+}
+
+impl_widening_scale!(u8; 0 1 2);
+impl_widening_scale!(i8; 0 1 2);
+impl_widening_scale!(u16; 0 1 2 3 4);
+impl_widening_scale!(i16; 0 1 2 3 4);
+impl_widening_scale!(u32; 0 1 2 3 4 5 6 7 8 9);
+impl_widening_scale!(i32; 0 1 2 3 4 5 6 7 8 9);
+impl_widening_scale!(u64; 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19);
+impl_widening_scale!(i64; 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18);
+impl_widening_scale!(
+    u128;
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
+    20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38
+);
+impl_widening_scale!(
+    i128;
+    0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
+    20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36 37 38
+);
+
+/// Computes `a * b / c` in the doubled-width intermediate without narrowing
+/// back to `Self`.
+trait WideMulDiv: WideningMul {
+    #[track_caller]
+    fn wide_mul_div(self, rhs: Self, div: Self) -> Self::Wide;
 }
 
 macro_rules! impl_primitive {
@@ -63,16 +154,76 @@ macro_rules! impl_primitive {
                     return None;
                 }
 
-                // The intermediate type has twice the width of the primary
-                // type, so neither the product nor the division can overflow.
+                Self::wide_mul_div(self, rhs, div).try_into().ok()
+            }
+
+            #[inline]
+            #[track_caller]
+            fn try_full_mul_div_round(
+                self,
+                rhs: Self,
+                div: Self,
+                mode: RoundingMode,
+            ) -> Option<Self> {
+                if div == 0 {
+                    return None;
+                }
+
                 let numer = <$intermediate>::from(self)
                     .checked_mul(<$intermediate>::from(rhs))
                     .expect("doubled-width product cannot overflow");
-                let out = numer
-                    .checked_div(<$intermediate>::from(div))
-                    .expect("divisor checked non-zero above");
 
-                out.try_into().ok()
+                round_div(numer, <$intermediate>::from(div), mode)
+                    .try_into()
+                    .ok()
+            }
+
+            #[inline]
+            #[track_caller]
+            fn overflowing_full_mul_div(self, rhs: Self, div: Self) -> (Self, bool) {
+                let out = Self::wide_mul_div(self, rhs, div);
+
+                (out as $primary, <$primary>::try_from(out).is_err())
+            }
+
+            #[inline]
+            #[track_caller]
+            fn saturating_full_mul_div(self, rhs: Self, div: Self) -> Self {
+                let out = Self::wide_mul_div(self, rhs, div);
+
+                // If the output does not fit then it must be beyond one of the bounds.
+                out.try_into()
+                    .unwrap_or(match out > <$intermediate>::from(<$primary>::MAX) {
+                        true => <$primary>::MAX,
+                        false => <$primary>::MIN,
+                    })
+            }
+        }
+
+        impl WideningMul for $primary {
+            type Wide = $intermediate;
+
+            #[inline]
+            fn widening_mul(self, rhs: Self) -> $intermediate {
+                <$intermediate>::from(self)
+                    .checked_mul(<$intermediate>::from(rhs))
+                    .expect("doubled-width product cannot overflow")
+            }
+        }
+
+        impl WideMulDiv for $primary {
+            #[inline]
+            #[track_caller]
+            fn wide_mul_div(self, rhs: Self, div: Self) -> $intermediate {
+                if div == 0 {
+                    division_by_zero(self, rhs, div);
+                }
+
+                // The intermediate type has twice the width of the primary
+                // type, so neither the product nor the division can overflow.
+                WideningMul::widening_mul(self, rhs)
+                    .checked_div(<$intermediate>::from(div))
+                    .expect("divisor checked non-zero above")
             }
         }
     };
@@ -108,13 +259,76 @@ impl FullMulDiv for u128 {
             return None;
         }
 
-        let out: U256 = Uint::from(self)
-            .checked_mul(Uint::from(rhs))
-            .expect("two u128 always fit U256")
-            .checked_div(Uint::from(div))
+        // If we can compute the output using only a u128, then we should.
+        if let Some(numer) = self.checked_mul(rhs) {
+            return numer.checked_div(div);
+        }
+
+        mixed_mul_div(self, rhs, div, 0, RoundingMode::TowardZero)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn try_full_mul_div_round(self, rhs: Self, div: Self, mode: RoundingMode) -> Option<Self> {
+        if div == 0 {
+            return None;
+        }
+
+        // If we can compute the output using only a u128, then we should.
+        if let Some(numer) = self.checked_mul(rhs) {
+            return Some(round_div(numer, div, mode));
+        }
+
+        mixed_mul_div(self, rhs, div, 0, mode)
+    }
+
+    #[inline]
+    #[track_caller]
+    fn overflowing_full_mul_div(self, rhs: Self, div: Self) -> (Self, bool) {
+        if div == 0 {
+            division_by_zero(self, rhs, div);
+        }
+
+        let (_, out) = mul_div_magnitude(self, rhs, div, 0, RoundingMode::TowardZero)
             .expect("divisor checked non-zero above");
 
-        out.try_into().ok()
+        (out.wrapping_to(), out > U512::from(u128::MAX))
+    }
+
+    #[inline]
+    #[track_caller]
+    fn saturating_full_mul_div(self, rhs: Self, div: Self) -> Self {
+        if div == 0 {
+            division_by_zero(self, rhs, div);
+        }
+
+        self.try_full_mul_div(rhs, div).unwrap_or(u128::MAX)
+    }
+}
+
+impl WideningMul for u128 {
+    type Wide = U256;
+
+    #[inline]
+    fn widening_mul(self, rhs: Self) -> U256 {
+        U256::from(self)
+            .checked_mul(U256::from(rhs))
+            .expect("two u128 always fit U256")
+    }
+}
+
+impl WideningMul for i128 {
+    type Wide = I256;
+
+    #[inline]
+    fn widening_mul(self, rhs: Self) -> I256 {
+        let magnitude = U256::from(self.unsigned_abs())
+            .checked_mul(U256::from(rhs.unsigned_abs()))
+            .expect("two i128 magnitudes always fit U256");
+
+        // The magnitude is at most 2^254, which always fits `I256`.
+        I256::from_sign_magnitude(self.is_negative() != rhs.is_negative(), magnitude)
+            .expect("two i128 always fit I256")
     }
 }
 
@@ -149,53 +363,238 @@ impl FullMulDiv for i128 {
             return Some(out);
         }
 
-        // Determine the sign of the output. Signum returns -1, 0, +1, therefore
-        // overflow is not possible. Additionally, if `self` or `rhs` are zero then we
-        // will have already returned, and `div` was checked non-zero above.
-        #[allow(clippy::arithmetic_side_effects)]
-        let sign = self.signum() * rhs.signum() * div.signum();
+        mixed_mul_div(self, rhs, div, 0, RoundingMode::TowardZero)
+    }
 
-        // Get the unsigned u256 representation of the integer (we'll later recover the
-        // signed representation using two's complement).
-        let this = U256::from(self.unsigned_abs());
-        let rhs = U256::from(rhs.unsigned_abs());
-        let div = U256::from(div.unsigned_abs());
+    #[inline]
+    #[track_caller]
+    fn try_full_mul_div_round(self, rhs: Self, div: Self, mode: RoundingMode) -> Option<Self> {
+        if div == 0 {
+            return None;
+        }
 
-        // Compute the unsigned output.
-        let unsigned = this
-            .checked_mul(rhs)
-            .expect("two i128 magnitudes always fit U256")
-            .checked_div(div)
-            .expect("divisor checked non-zero above");
+        // If we can compute the output using only an i128, then we should. Rounding
+        // requires the magnitude of `div` so `i128::MIN` takes the wide path.
+        if let Some(numer) = self.checked_mul(rhs)
+            && div != i128::MIN
+            && numer.checked_div(div).is_some()
+        {
+            return Some(round_div(numer, div, mode));
+        }
 
-        // Convert back to the signed output.
-        match sign {
-            1 => i128::try_from(unsigned).ok(),
-            -1 => {
-                let unsigned = u128::try_from(unsigned).ok()?;
-                // The most negative representable magnitude is 2^127; larger
-                // magnitudes would silently wrap through two's complement.
-                if unsigned > 1u128 << 127 {
-                    return None;
-                }
+        mixed_mul_div(self, rhs, div, 0, mode)
+    }
 
-                // Take two's complement (!unsigned + 1).
-                // https://en.wikipedia.org/wiki/Two%27s_complement.
-                let twos_complement = (!unsigned).overflowing_add(1).0;
+    #[inline]
+    #[track_caller]
+    fn overflowing_full_mul_div(self, rhs: Self, div: Self) -> (Self, bool) {
+        if div == 0 {
+            division_by_zero(self, rhs, div);
+        }
 
-                Some(i128::from_le_bytes(twos_complement.to_le_bytes()))
+        let (negative, unsigned) = mul_div_magnitude(self, rhs, div, 0, RoundingMode::TowardZero)
+            .expect("divisor checked non-zero above");
+        match u128::try_from(unsigned)
+            .ok()
+            .and_then(|unsigned| from_sign_magnitude(negative, unsigned))
+        {
+            Some(out) => (out, false),
+            None => {
+                // Wrapping the magnitude before negating is equivalent to wrapping the
+                // full width two's complement representation.
+                let wrapped = unsigned.wrapping_to::<u128>() as i128;
+
+                match negative {
+                    true => (wrapped.wrapping_neg(), true),
+                    false => (wrapped, true),
+                }
             }
-            _ => unreachable!(),
         }
     }
+
+    #[inline]
+    #[track_caller]
+    fn saturating_full_mul_div(self, rhs: Self, div: Self) -> Self {
+        if div == 0 {
+            division_by_zero(self, rhs, div);
+        }
+
+        let negative = self.is_negative() ^ rhs.is_negative() ^ div.is_negative();
+
+        self.try_full_mul_div(rhs, div).unwrap_or(match negative {
+            true => i128::MIN,
+            false => i128::MAX,
+        })
+    }
+}
+
+/// Splits a primitive integer into its sign and magnitude.
+pub(crate) fn sign_magnitude<I>(value: I) -> (bool, u128)
+where
+    I: PrimInt,
+{
+    match value.to_i128() {
+        Some(value) => (value < 0, value.unsigned_abs()),
+        None => (
+            false,
+            value
+                .to_u128()
+                .expect("primitive integers fit u128 or i128"),
+        ),
+    }
+}
+
+/// Rebuilds a primitive integer from its sign and magnitude, returning `None`
+/// if the value does not fit.
+pub(crate) fn from_sign_magnitude<O>(negative: bool, magnitude: u128) -> Option<O>
+where
+    O: PrimInt,
+{
+    match negative {
+        true => num_traits::cast(0i128.checked_sub_unsigned(magnitude)?),
+        false => num_traits::cast(magnitude),
+    }
+}
+
+/// Computes `|numer / div * 10^exponent|` with a single rounding step.
+///
+/// Returns `None` if `div` is zero or the rescaled operands exceed a `U512`.
+fn scale_round(
+    negative: bool,
+    mut numer: U512,
+    mut div: U512,
+    exponent: i32,
+    mode: RoundingMode,
+) -> Option<U512> {
+    if div.is_zero() {
+        return None;
+    }
+
+    // Both sides hold at most a u128 times a few powers of ten (bounded by
+    // the maximum scale of each backing), so neither can realistically reach
+    // 2^512; we still bail out rather than wrap.
+    let factor = U512::from(10).checked_pow(U512::from(exponent.unsigned_abs()))?;
+    match exponent.is_negative() {
+        true => div = div.checked_mul(factor)?,
+        false => numer = numer.checked_mul(factor)?,
+    }
+
+    Some(round_div_uint(numer, div, negative, mode))
+}
+
+/// Computes `numer / div * 10^exponent` with a single rounding step and
+/// narrows the result to `O`.
+///
+/// Returns `None` if `div` is zero or the result does not fit `O`.
+pub(crate) fn scale_round_into<O>(
+    negative: bool,
+    numer: U512,
+    div: U512,
+    exponent: i32,
+    mode: RoundingMode,
+) -> Option<O>
+where
+    O: PrimInt,
+{
+    let magnitude = scale_round(negative, numer, div, exponent, mode)?;
+
+    from_sign_magnitude(negative, u128::try_from(magnitude).ok()?)
+}
+
+/// Computes `|lhs * rhs / div * 10^exponent|` across arbitrary primitive
+/// integers alongside the sign of the result, rounding once at full width.
+///
+/// This is the single full-width path shared by [`FullMulDiv`] for the 128 bit
+/// backings and by the mixed-backing operations.
+///
+/// Returns `None` if `div` is zero or the rescaled operands exceed a `U512`.
+fn mul_div_magnitude<A, B, C>(
+    lhs: A,
+    rhs: B,
+    div: C,
+    exponent: i32,
+    mode: RoundingMode,
+) -> Option<(bool, U512)>
+where
+    A: PrimInt,
+    B: PrimInt,
+    C: PrimInt,
+{
+    let (lhs_negative, lhs) = sign_magnitude(lhs);
+    let (rhs_negative, rhs) = sign_magnitude(rhs);
+    let (div_negative, div) = sign_magnitude(div);
+    let numer = U512::from(lhs)
+        .checked_mul(U512::from(rhs))
+        .expect("two u128 always fit U512");
+    let negative = lhs_negative ^ rhs_negative ^ div_negative;
+
+    Some((negative, scale_round(negative, numer, U512::from(div), exponent, mode)?))
+}
+
+/// Computes `lhs * rhs / div * 10^exponent` across arbitrary primitive
+/// integers, rounding once at full width.
+///
+/// Returns `None` if `div` is zero or the result does not fit `O`.
+pub(crate) fn mixed_mul_div<A, B, C, O>(
+    lhs: A,
+    rhs: B,
+    div: C,
+    exponent: i32,
+    mode: RoundingMode,
+) -> Option<O>
+where
+    A: PrimInt,
+    B: PrimInt,
+    C: PrimInt,
+    O: PrimInt,
+{
+    let (negative, magnitude) = mul_div_magnitude(lhs, rhs, div, exponent, mode)?;
+
+    from_sign_magnitude(negative, u128::try_from(magnitude).ok()?)
+}
+
+/// Computes `(lhs * 10^lhs_shift + rhs * 10^rhs_shift) * 10^exponent` across
+/// arbitrary primitive integers, rounding once at full width.
+///
+/// Returns `None` if the result does not fit `O`.
+pub(crate) fn mixed_add<A, B, O>(
+    lhs: A,
+    lhs_shift: u32,
+    rhs: B,
+    rhs_shift: u32,
+    exponent: i32,
+    mode: RoundingMode,
+) -> Option<O>
+where
+    A: PrimInt,
+    B: PrimInt,
+    O: PrimInt,
+{
+    let ten = U512::from(10);
+    let (lhs_negative, lhs) = sign_magnitude(lhs);
+    let (rhs_negative, rhs) = sign_magnitude(rhs);
+    let lhs = U512::from(lhs).checked_mul(ten.checked_pow(U512::from(lhs_shift))?)?;
+    let rhs = U512::from(rhs).checked_mul(ten.checked_pow(U512::from(rhs_shift))?)?;
+
+    // Add the magnitudes when the signs agree, otherwise subtract the smaller
+    // from the larger and keep the sign of the larger.
+    let (negative, sum) = match (lhs_negative == rhs_negative, lhs >= rhs) {
+        (true, _) => (lhs_negative, lhs.checked_add(rhs)?),
+        (false, true) => (lhs_negative, lhs - rhs),
+        (false, false) => (rhs_negative, rhs - lhs),
+    };
+
+    scale_round_into(negative, sum, U512::ONE, exponent, mode)
 }
 
 #[cfg(test)]
 mod tests {
-    use malachite::Integer;
+    use malachite::base::num::conversion::traits::WrappingFrom;
+    use malachite::{Integer, Rational};
     use proptest::prelude::*;
 
     use super::*;
+    use crate::rounding::reference_round;
 
     #[test]
     fn u128_full_mul_div() {
@@ -246,6 +645,133 @@ mod tests {
         });
     }
 
+    #[test]
+    fn u128_overflowing_full_mul_div() {
+        proptest!(|(a: u128, b: u128, div: u128)| {
+            if div == 0 {
+                return Ok(());
+            }
+
+            let reference = Integer::from(a) * Integer::from(b) / Integer::from(div);
+            let wrapped = u128::wrapping_from(&reference);
+            let overflow = u128::try_from(&reference).is_err();
+
+            assert_eq!(u128::overflowing_full_mul_div(a, b, div), (wrapped, overflow));
+            assert_eq!(
+                u128::saturating_full_mul_div(a, b, div),
+                u128::try_from(&reference).unwrap_or(u128::MAX),
+            );
+        });
+    }
+
+    #[test]
+    fn i128_overflowing_full_mul_div() {
+        proptest!(|(a: i128, b: i128, div: i128)| {
+            if div == 0 {
+                return Ok(());
+            }
+
+            let reference = Integer::from(a) * Integer::from(b) / Integer::from(div);
+            let wrapped = i128::wrapping_from(&reference);
+            let overflow = i128::try_from(&reference).is_err();
+            let saturated = match reference < 0 {
+                true => i128::MIN,
+                false => i128::MAX,
+            };
+
+            assert_eq!(i128::overflowing_full_mul_div(a, b, div), (wrapped, overflow));
+            assert_eq!(
+                i128::saturating_full_mul_div(a, b, div),
+                i128::try_from(&reference).unwrap_or(saturated),
+            );
+        });
+    }
+
+    #[test]
+    fn i64_saturating_full_mul_div() {
+        assert_eq!(i64::saturating_full_mul_div(i64::MAX, 2, 1), i64::MAX);
+        assert_eq!(i64::saturating_full_mul_div(i64::MAX, -2, 1), i64::MIN);
+        assert_eq!(i64::saturating_full_mul_div(i64::MIN, -1, 1), i64::MAX);
+        assert_eq!(i64::saturating_full_mul_div(i64::MIN, 1, -1), i64::MAX);
+        assert_eq!(u64::saturating_full_mul_div(u64::MAX, 2, 1), u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero; lhs=1; rhs=2; div=0")]
+    fn i128_overflowing_full_mul_div_division_by_zero() {
+        i128::overflowing_full_mul_div(1, 2, 0);
+    }
+
+    #[test]
+    fn u128_try_full_mul_div_round() {
+        proptest!(|(a: u128, b: u128, div: u128, mode: RoundingMode)| {
+            if div == 0 {
+                return Ok(());
+            }
+
+            let exact = Rational::from_integers(Integer::from(a) * Integer::from(b), Integer::from(div));
+            let reference = u128::try_from(&reference_round(&exact, mode)).ok();
+
+            assert_eq!(u128::try_full_mul_div_round(a, b, div, mode), reference);
+        });
+    }
+
+    #[test]
+    fn i128_try_full_mul_div_round() {
+        proptest!(|(a: i128, b: i128, div: i128, mode: RoundingMode)| {
+            if div == 0 {
+                return Ok(());
+            }
+
+            let exact = Rational::from_integers(Integer::from(a) * Integer::from(b), Integer::from(div));
+            let reference = i128::try_from(&reference_round(&exact, mode)).ok();
+
+            assert_eq!(i128::try_full_mul_div_round(a, b, div, mode), reference);
+        });
+    }
+
+    #[test]
+    fn i128_try_full_mul_div_round_narrow() {
+        proptest!(|(a: i64, b: i64, div: i64, mode: RoundingMode)| {
+            if div == 0 {
+                return Ok(());
+            }
+
+            // Exercise the narrow path with the same reference.
+            let exact = Rational::from_integers(Integer::from(a) * Integer::from(b), Integer::from(div));
+            let reference = i128::try_from(&reference_round(&exact, mode)).ok();
+
+            assert_eq!(
+                i128::try_full_mul_div_round(i128::from(a), i128::from(b), i128::from(div), mode),
+                reference,
+            );
+        });
+    }
+
+    #[test]
+    fn i8_try_full_mul_div_round() {
+        proptest!(|(a: i8, b: i8, div: i8, mode: RoundingMode)| {
+            if div == 0 {
+                return Ok(());
+            }
+
+            let exact = Rational::from_integers(Integer::from(a) * Integer::from(b), Integer::from(div));
+            let reference = i8::try_from(&reference_round(&exact, mode)).ok();
+
+            assert_eq!(i8::try_full_mul_div_round(a, b, div, mode), reference);
+        });
+    }
+
+    #[test]
+    fn i128_try_full_mul_div_round_min_divisor() {
+        assert_eq!(i128::try_full_mul_div_round(3, 1, i128::MIN, RoundingMode::Floor), Some(-1),);
+        assert_eq!(
+            i128::try_full_mul_div_round(i128::MIN, 1, i128::MIN, RoundingMode::Floor),
+            Some(1),
+        );
+        assert_eq!(i128::try_full_mul_div_round(i128::MIN, 1, -1, RoundingMode::HalfEven), None,);
+    }
+
     #[test]
     fn try_full_mul_div_returns_none_for_zero_divisor() {
         assert_eq!(i64::try_full_mul_div(1, 2, 0), None);
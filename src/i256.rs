@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+use num_traits::PrimInt;
+use ruint::aliases::U256;
+use thiserror::Error;
+
+use crate::full_mul_div::sign_magnitude;
+
+const SIGN_BIT: U256 = U256::ONE.wrapping_shl(255);
+
+/// Signed 256-bit two's complement integer.
+///
+/// Only provides what is needed to hold the exact product of two `i128`
+/// backings (see [`WideningMul`](crate::WideningMul)).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct I256(U256);
+
+impl I256 {
+    pub const ZERO: I256 = I256(U256::ZERO);
+    pub const MIN: I256 = I256(SIGN_BIT);
+    pub const MAX: I256 = I256(U256::MAX.wrapping_shr(1));
+
+    /// Builds an `I256` from its raw two's complement bits.
+    #[must_use]
+    pub const fn from_bits(bits: U256) -> Self {
+        I256(bits)
+    }
+
+    /// Returns the raw two's complement bits.
+    #[must_use]
+    pub const fn to_bits(self) -> U256 {
+        self.0
+    }
+
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.0.bit(255)
+    }
+
+    /// Returns the magnitude, which always fits a `U256` (including for
+    /// [`I256::MIN`]).
+    #[must_use]
+    pub const fn unsigned_abs(self) -> U256 {
+        match self.is_negative() {
+            true => self.0.wrapping_neg(),
+            false => self.0,
+        }
+    }
+
+    /// Builds an `I256` from a sign & magnitude pair, returning `None` if the
+    /// value does not fit.
+    #[must_use]
+    pub fn from_sign_magnitude(negative: bool, magnitude: U256) -> Option<Self> {
+        match negative {
+            false => (!magnitude.bit(255)).then_some(I256(magnitude)),
+            true => (magnitude <= I256::MIN.0).then(|| I256(magnitude.wrapping_neg())),
+        }
+    }
+
+    /// Widens any primitive integer, which always fits.
+    pub(crate) fn from_primitive<T>(value: T) -> Self
+    where
+        T: PrimInt,
+    {
+        let (negative, magnitude) = sign_magnitude(value);
+
+        I256::from_sign_magnitude(negative, U256::from(magnitude))
+            .expect("every primitive magnitude fits `I256`")
+    }
+
+    /// Adds `rhs`, returning `None` on overflow.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        let out = I256(self.0.wrapping_add(rhs.0));
+
+        // Overflow iff both operands share a sign that the result does not.
+        match self.is_negative() == rhs.is_negative() && out.is_negative() != self.is_negative() {
+            true => None,
+            false => Some(out),
+        }
+    }
+}
+
+impl From<i128> for I256 {
+    fn from(value: i128) -> Self {
+        let magnitude = U256::from(value.unsigned_abs());
+
+        match value.is_negative() {
+            true => I256(magnitude.wrapping_neg()),
+            false => I256(magnitude),
+        }
+    }
+}
+
+/// Error returned when an [`I256`] does not fit the target integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("`I256` does not fit the target integer")]
+pub struct TryFromI256Error;
+
+impl TryFrom<I256> for i128 {
+    type Error = TryFromI256Error;
+
+    fn try_from(value: I256) -> Result<Self, Self::Error> {
+        let out = u128::try_from(value.unsigned_abs())
+            .ok()
+            .and_then(|magnitude| match value.is_negative() {
+                true => 0i128.checked_sub_unsigned(magnitude),
+                false => i128::try_from(magnitude).ok(),
+            });
+
+        out.ok_or(TryFromI256Error)
+    }
+}
+
+impl PartialOrd for I256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for I256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Flipping the sign bit maps two's complement onto an order preserving
+        // unsigned representation.
+        (self.0 ^ SIGN_BIT).cmp(&(other.0 ^ SIGN_BIT))
+    }
+}
+
+impl Display for I256 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_negative() {
+            f.write_str("-")?;
+        }
+
+        write!(f, "{}", self.unsigned_abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn bounds() {
+        assert_eq!(I256::MIN.to_string(), format!("-{}", U256::ONE.wrapping_shl(255)));
+        assert_eq!(I256::MAX.to_string(), U256::MAX.wrapping_shr(1).to_string());
+        assert!(I256::MIN < I256::ZERO && I256::ZERO < I256::MAX);
+        assert_eq!(I256::from_sign_magnitude(true, U256::ONE.wrapping_shl(255)), Some(I256::MIN));
+        assert_eq!(I256::from_sign_magnitude(false, U256::ONE.wrapping_shl(255)), None);
+        assert_eq!(I256::MAX.checked_add(I256::from(1)), None);
+        assert_eq!(I256::MIN.checked_add(I256::from(-1)), None);
+        assert_eq!(I256::MIN.checked_add(I256::MAX), Some(I256::from(-1)));
+    }
+
+    #[test]
+    fn i128_round_trip() {
+        proptest!(|(a: i128, b: i128)| {
+            let (wide_a, wide_b) = (I256::from(a), I256::from(b));
+
+            assert_eq!(wide_a.to_string(), a.to_string());
+            assert_eq!(i128::try_from(wide_a).unwrap(), a);
+            assert_eq!(wide_a.cmp(&wide_b), a.cmp(&b));
+            let sum = wide_a.checked_add(wide_b).unwrap();
+            assert_eq!(i128::try_from(sum).ok(), a.checked_add(b));
+        });
+
+        assert_eq!(
+            i128::try_from(I256::from(i128::MAX).checked_add(I256::from(1)).unwrap()),
+            Err(TryFromI256Error)
+        );
+        assert_eq!(i128::try_from(I256::MIN), Err(TryFromI256Error));
+    }
+}
@@ -3,7 +3,11 @@ use std::num::ParseIntError;
 use std::ops::{AddAssign, DivAssign, Not, Shr};
 use std::str::FromStr;
 
-use num_traits::{CheckedNeg, CheckedRem, ConstOne, ConstZero, One, PrimInt, WrappingAdd};
+use num_traits::ops::overflowing::{OverflowingAdd, OverflowingSub};
+use num_traits::{
+    CheckedNeg, CheckedRem, ConstOne, ConstZero, One, PrimInt, WrappingAdd, WrappingMul,
+    WrappingSub,
+};
 
 use crate::cheats::Cheats;
 use crate::full_mul_div::FullMulDiv;
@@ -14,6 +18,10 @@ pub trait ScaledInteger<const D: u8>:
     + ConstOne
     + One
     + WrappingAdd<Output = Self>
+    + WrappingSub<Output = Self>
+    + WrappingMul<Output = Self>
+    + OverflowingAdd
+    + OverflowingSub
     + CheckedRem<Output = Self>
     + Not<Output = Self>
     + Shr<u32, Output = Self>
@@ -32,6 +40,10 @@ impl<I, const D: u8> ScaledInteger<D> for I where
         + ConstOne
         + One
         + WrappingAdd<Output = Self>
+        + WrappingSub<Output = Self>
+        + WrappingMul<Output = Self>
+        + OverflowingAdd
+        + OverflowingSub
         + CheckedRem<Output = Self>
         + Not<Output = Self>
         + Shr<u32, Output = Self>
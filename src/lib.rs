@@ -1,28 +1,59 @@
+/// Overflow-free summation of many decimals.
+mod accumulator;
+/// Fixed-point transcendental functions.
+mod algorithms;
 /// Some balanced [`Decimal`] variants.
 mod aliases;
+/// Pro-rata allocation without losing units.
+mod allocation;
 /// Stuff that should be done generically if I had more time.
 mod cheats;
+/// `const fn` construction & arithmetic for each primitive backing.
+mod const_fn;
 /// Casts between cost-decimals.
 mod conversion;
 /// Core decimal type & operations.
 mod decimal;
 /// [`Display`] and [`FromStr`] implementation.
 mod display;
+/// Errors returned by checked operations.
+mod error;
 /// Implementations of foreign traits.
 mod foreign_traits;
 /// Full multiplication implementations for underlying integers.
 mod full_mul_div;
+/// Signed 256-bit integer backing widened `i128` decimals.
+mod i256;
 /// Trait definition for underlying integer.
 mod integer;
+/// The [`dec!`] macro for compile-time checked literals.
+mod literal;
+/// Arithmetic across differing backings & scales.
+mod mixed;
+/// Basis-point & percentage rates.
+mod rate;
+/// Square & n-th roots.
+mod root;
+/// Rounding modes for operations that discard precision.
+mod rounding;
+/// Exact statistics over decimals.
+pub mod stats;
 /// Macros used in tests.
 #[cfg(test)]
 #[macro_use]
 pub(crate) mod macros;
-/// Underlying algorithms used by other operations.
-#[cfg(test)]
-pub(crate) mod algorithms;
 
+pub use accumulator::{AccumulatorError, DecimalAccumulator, WideAccumulate};
 pub use aliases::*;
+pub use allocation::{AllocationError, TieBreak};
+pub use conversion::{TryFromDecimalError, TryFromFloatError};
 pub use decimal::*;
 pub use display::ParseDecimalError;
+pub use error::*;
+pub use full_mul_div::{WideningMul, WideningScale};
+pub use i256::{I256, TryFromI256Error};
 pub use integer::*;
+#[doc(hidden)]
+pub use literal::{FromLiteral, parse_literal};
+pub use rate::{Bps, ParseRateError, Percent};
+pub use rounding::RoundingMode;
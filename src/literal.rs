@@ -0,0 +1,148 @@
+use crate::Decimal;
+use crate::cheats::Cheats;
+use crate::const_fn::{scale_digits, split_decimal};
+
+/// Creates a [`Decimal`] from a literal that is checked at compile time.
+///
+/// The backing & scale are inferred unless given explicitly as
+/// `dec!(literal, backing, scale)`. The raw value matches what [`FromStr`]
+/// would parse, but a literal with more fractional digits than the scale or
+/// one that overflows the backing fails to compile rather than at runtime.
+///
+/// [`FromStr`]: std::str::FromStr
+///
+/// # Examples
+///
+/// ```rust
+/// use const_decimal::{Decimal, dec};
+///
+/// const FEE: Decimal<i64, 9> = dec!(0.0025);
+/// static LIMIT: Decimal<u32, 2> = dec!(1_000.5);
+///
+/// assert_eq!(FEE, "0.0025".parse().unwrap());
+/// assert_eq!(LIMIT, Decimal(100_050));
+/// assert_eq!(dec!(-11.65, i64, 9), Decimal::<i64, 9>::try_from_scaled(-1165, 2).unwrap());
+/// ```
+///
+/// Literals that do not fit are rejected by the compiler:
+///
+/// ```rust,compile_fail
+/// use const_decimal::{Decimal, dec};
+///
+/// let _: Decimal<i64, 1> = dec!(11.65);
+/// ```
+///
+/// ```rust,compile_fail
+/// use const_decimal::dec;
+///
+/// let _ = dec!(1.28, i8, 2);
+/// ```
+#[macro_export]
+macro_rules! dec {
+    ($literal:literal) => {
+        <_ as $crate::FromLiteral<
+            { $crate::parse_literal(stringify!($literal)).0 },
+            { $crate::parse_literal(stringify!($literal)).1 },
+            { $crate::parse_literal(stringify!($literal)).2 },
+        >>::VALUE
+    };
+    ($literal:literal, $backing:ty, $scale:expr) => {
+        <$crate::Decimal<$backing, $scale> as $crate::FromLiteral<
+            { $crate::parse_literal(stringify!($literal)).0 },
+            { $crate::parse_literal(stringify!($literal)).1 },
+            { $crate::parse_literal(stringify!($literal)).2 },
+        >>::VALUE
+    };
+}
+
+/// A decimal literal, parsed by [`parse_literal`] into its sign, digits & the
+/// number of fractional digits, evaluated at compile time.
+#[doc(hidden)]
+pub trait FromLiteral<const NEGATIVE: bool, const DIGITS: u128, const FRACTIONAL: u8> {
+    const VALUE: Self;
+}
+
+/// Splits a decimal literal into its sign, its digits (ignoring the decimal
+/// point) & the number of fractional digits.
+///
+/// # Panics
+///
+/// Panics (i.e. fails to compile when used by [`dec!`]) if `literal` is not a
+/// plain decimal number.
+#[doc(hidden)]
+#[must_use]
+pub const fn parse_literal(literal: &str) -> (bool, u128, u8) {
+    match split_decimal(literal.as_bytes(), true) {
+        Some(parts) => parts,
+        None => panic!("expected a decimal literal such as `-11.65`"),
+    }
+}
+
+macro_rules! impl_from_literal {
+    ($($int:ty),+) => {
+        $(
+            impl<const D: u8, const NEGATIVE: bool, const DIGITS: u128, const FRACTIONAL: u8>
+                FromLiteral<NEGATIVE, DIGITS, FRACTIONAL> for Decimal<$int, D>
+            {
+                const VALUE: Self = {
+                    let () = <$int as Cheats<D>>::ASSERT_SCALE;
+                    assert!(
+                        FRACTIONAL <= D,
+                        "decimal literal has more fractional digits than the scale"
+                    );
+
+                    let value = match scale_digits(DIGITS, FRACTIONAL, D) {
+                        Some(magnitude) => Self::const_from_sign_magnitude(NEGATIVE, magnitude),
+                        None => None,
+                    };
+                    match value {
+                        Some(value) => value,
+                        None => panic!("decimal literal overflows the backing integer"),
+                    }
+                };
+            }
+        )+
+    };
+}
+
+impl_from_literal!(u8, i8, u16, i16, u32, i32, u64, i64, u128, i128);
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decimal, Int64_9, Uint64_9};
+
+    /// Asserts that each literal produces the same decimal as parsing it.
+    macro_rules! assert_matches_from_str {
+        ($backing:ty, $scale:literal; $($literal:literal),+ $(,)?) => {
+            $(
+                assert_eq!(
+                    Ok(dec!($literal, $backing, $scale)),
+                    stringify!($literal).parse::<Decimal<$backing, $scale>>(),
+                );
+            )+
+        };
+    }
+
+    const ONE: Int64_9 = dec!(1);
+    static MIN: Decimal<i8, 1> = dec!(-12.8);
+
+    #[test]
+    fn matches_from_str() {
+        assert_matches_from_str!(i64, 9; 0, -0, 1.0, 11.65, -11.65, 0.000000001, -9223372036.854775807);
+        assert_matches_from_str!(u64, 9; 0.5, 18446744073.709551615, 007.10);
+        assert_matches_from_str!(i128, 18; -170141183460469231731.687303715884105727);
+        assert_matches_from_str!(u128, 0; 340282366920938463463374607431768211455);
+        assert_matches_from_str!(u8, 2; 2.55, 0.01, -0, -0.00);
+        assert_matches_from_str!(i8, 1; -12.8, -0.1);
+        assert_matches_from_str!(i128, 0; -170141183460469231731687303715884105728);
+    }
+
+    #[test]
+    fn const_and_static() {
+        let price: Uint64_9 = dec!(1_165.000_1);
+
+        assert_eq!(ONE, Int64_9::ONE);
+        assert_eq!(MIN, Decimal::MIN);
+        assert_eq!(price, Decimal(1_165_000_100_000));
+    }
+}
@@ -0,0 +1,271 @@
+use crate::full_mul_div::{mixed_add, mixed_mul_div};
+use crate::{Decimal, DecimalError, I256, Operation, RoundingMode, ScaledInteger};
+
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    /// Multiplies two decimals of arbitrary backing & scale, producing `Self`.
+    ///
+    /// The product is computed at full width and rounded once according to
+    /// `mode`. Returns an error if the result does not fit `Self`.
+    ///
+    /// As the operands may have different backings, errors report them widened
+    /// to an [`I256`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let price = "101.25".parse::<Decimal<i64, 8>>().unwrap();
+    /// let quantity = "-3.5".parse::<Decimal<i64, 4>>().unwrap();
+    ///
+    /// let notional = Decimal::<i128, 12>::mul_into(price, quantity, RoundingMode::HalfEven);
+    /// assert_eq!(notional, Ok("-354.375".parse().unwrap()));
+    /// ```
+    pub fn mul_into<I1, const D1: u8, I2, const D2: u8>(
+        lhs: Decimal<I1, D1>,
+        rhs: Decimal<I2, D2>,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError<I256>>
+    where
+        I1: ScaledInteger<D1>,
+        I2: ScaledInteger<D2>,
+    {
+        let exponent = i32::from(D) - i32::from(D1) - i32::from(D2);
+
+        // NB: Either operand may be wider than `I` and the rescaling factor (up
+        // to `10^76`) fits no backing, so this goes through the same `U512` path
+        // that backs `FullMulDiv` for the 128 bit backings.
+        mixed_mul_div(lhs.0, rhs.0, 1u8, exponent, mode)
+            .map(Decimal)
+            .ok_or_else(|| out_of_range(Operation::Mul, lhs, rhs))
+    }
+
+    /// Divides two decimals of arbitrary backing & scale, producing `Self`.
+    ///
+    /// The quotient is computed at full width and rounded once according to
+    /// `mode`. Returns an error if `rhs` is zero or the result does not fit
+    /// `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let notional = "100".parse::<Decimal<i128, 12>>().unwrap();
+    /// let price = "3".parse::<Decimal<i64, 8>>().unwrap();
+    ///
+    /// let quantity = Decimal::<i64, 4>::div_into(notional, price, RoundingMode::Floor);
+    /// assert_eq!(quantity, Ok("33.3333".parse().unwrap()));
+    /// ```
+    pub fn div_into<I1, const D1: u8, I2, const D2: u8>(
+        lhs: Decimal<I1, D1>,
+        rhs: Decimal<I2, D2>,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError<I256>>
+    where
+        I1: ScaledInteger<D1>,
+        I2: ScaledInteger<D2>,
+    {
+        if rhs.0 == I2::ZERO {
+            return Err(DecimalError::DivisionByZero {
+                op: Operation::Div,
+                lhs: I256::from_primitive(lhs.0),
+                rhs: I256::ZERO,
+            });
+        }
+
+        let exponent = i32::from(D) + i32::from(D2) - i32::from(D1);
+
+        mixed_mul_div(lhs.0, 1u8, rhs.0, exponent, mode)
+            .map(Decimal)
+            .ok_or_else(|| out_of_range(Operation::Div, lhs, rhs))
+    }
+
+    /// Adds two decimals of arbitrary backing & scale, producing `Self`.
+    ///
+    /// The sum is computed exactly at the larger of the two input scales and
+    /// rounded once according to `mode`. Returns an error if the result does
+    /// not fit `Self`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let balance = "10.5".parse::<Decimal<u64, 2>>().unwrap();
+    /// let fee = "-0.125".parse::<Decimal<i32, 3>>().unwrap();
+    ///
+    /// let out = Decimal::<i64, 2>::add_into(balance, fee, RoundingMode::HalfEven);
+    /// assert_eq!(out, Ok("10.38".parse().unwrap()));
+    /// ```
+    pub fn add_into<I1, const D1: u8, I2, const D2: u8>(
+        lhs: Decimal<I1, D1>,
+        rhs: Decimal<I2, D2>,
+        mode: RoundingMode,
+    ) -> Result<Self, DecimalError<I256>>
+    where
+        I1: ScaledInteger<D1>,
+        I2: ScaledInteger<D2>,
+    {
+        let common = std::cmp::max(D1, D2);
+        let exponent = i32::from(D) - i32::from(common);
+
+        mixed_add(lhs.0, u32::from(common - D1), rhs.0, u32::from(common - D2), exponent, mode)
+            .map(Decimal)
+            .ok_or_else(|| out_of_range(Operation::Add, lhs, rhs))
+    }
+}
+
+fn out_of_range<I1, const D1: u8, I2, const D2: u8>(
+    op: Operation,
+    lhs: Decimal<I1, D1>,
+    rhs: Decimal<I2, D2>,
+) -> DecimalError<I256>
+where
+    I1: ScaledInteger<D1>,
+    I2: ScaledInteger<D2>,
+{
+    DecimalError::OutOfRange {
+        op,
+        lhs: I256::from_primitive(lhs.0),
+        rhs: I256::from_primitive(rhs.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{Integer, Rational};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::rounding::reference_round;
+
+    #[test]
+    fn mul_into_notional() {
+        let price = "12345.6789".parse::<Decimal<i64, 8>>().unwrap();
+        let quantity = "0.0003".parse::<Decimal<i64, 4>>().unwrap();
+
+        assert_eq!(
+            Decimal::<i128, 12>::mul_into(price, quantity, RoundingMode::TowardZero),
+            Ok("3.70370367".parse().unwrap()),
+        );
+        assert_eq!(
+            Decimal::<i64, 2>::mul_into(price, quantity, RoundingMode::HalfUp),
+            Ok("3.70".parse().unwrap()),
+        );
+        assert_eq!(
+            Decimal::<i64, 2>::mul_into(price, quantity, RoundingMode::Ceiling),
+            Ok("3.71".parse().unwrap()),
+        );
+    }
+
+    #[test]
+    fn mixed_out_of_range() {
+        let negative = "-1".parse::<Decimal<i64, 8>>().unwrap();
+        let one = Decimal::<u8, 0>::ONE;
+
+        let max = I256::from_primitive(u128::MAX);
+
+        assert_eq!(
+            Decimal::<u64, 0>::mul_into(negative, one, RoundingMode::HalfEven),
+            Err(DecimalError::OutOfRange {
+                op: Operation::Mul,
+                lhs: I256::from(-100_000_000),
+                rhs: I256::from(1),
+            }),
+        );
+        assert_eq!(
+            Decimal::<u64, 0>::add_into(negative, one, RoundingMode::HalfEven),
+            Ok(Decimal(0))
+        );
+        assert_eq!(
+            Decimal::<u64, 0>::add_into(negative, Decimal::<u8, 0>(0), RoundingMode::HalfEven),
+            Err(DecimalError::OutOfRange {
+                op: Operation::Add,
+                lhs: I256::from(-100_000_000),
+                rhs: I256::ZERO,
+            }),
+        );
+        assert_eq!(
+            Decimal::<i8, 0>::mul_into(Decimal::<u128, 0>::MAX, one, RoundingMode::HalfEven),
+            Err(DecimalError::OutOfRange { op: Operation::Mul, lhs: max, rhs: I256::from(1) }),
+        );
+        assert_eq!(
+            Decimal::<i128, 0>::mul_into(Decimal::<u128, 0>::MAX, one, RoundingMode::HalfEven),
+            Err(DecimalError::OutOfRange { op: Operation::Mul, lhs: max, rhs: I256::from(1) }),
+        );
+        assert_eq!(
+            Decimal::<u128, 0>::mul_into(Decimal::<u128, 0>::MAX, one, RoundingMode::HalfEven),
+            Ok(Decimal::MAX),
+        );
+        assert_eq!(
+            Decimal::<i128, 0>::add_into(
+                Decimal::<i128, 0>::MIN,
+                Decimal::<i8, 0>(0),
+                RoundingMode::Floor
+            ),
+            Ok(Decimal::MIN),
+        );
+    }
+
+    #[test]
+    fn div_into_by_zero() {
+        let one = Decimal::<i64, 8>::ONE;
+        let zero = Decimal::<u32, 2>::ZERO;
+
+        assert_eq!(
+            Decimal::<i128, 12>::div_into(one, zero, RoundingMode::HalfEven),
+            Err(DecimalError::DivisionByZero {
+                op: Operation::Div,
+                lhs: I256::from(100_000_000),
+                rhs: I256::ZERO,
+            }),
+        );
+    }
+
+    #[test]
+    fn mixed_differential() {
+        mixed_ops_differential::<i64, 8, i64, 4, i128, 12>();
+        mixed_ops_differential::<i64, 8, i64, 4, i64, 2>();
+        mixed_ops_differential::<u64, 9, i32, 3, i64, 6>();
+        mixed_ops_differential::<i128, 18, u128, 20, i128, 18>();
+        mixed_ops_differential::<i8, 1, u8, 2, i16, 0>();
+        mixed_ops_differential::<u32, 0, i16, 4, u64, 9>();
+    }
+
+    fn mixed_ops_differential<I1, const D1: u8, I2, const D2: u8, I3, const D3: u8>()
+    where
+        I1: ScaledInteger<D1> + Arbitrary + 'static,
+        I2: ScaledInteger<D2> + Arbitrary + 'static,
+        I3: ScaledInteger<D3>,
+        Rational: From<Decimal<I1, D1>> + From<Decimal<I2, D2>> + From<Decimal<I3, D3>> + From<I3>,
+    {
+        let scale = <Rational as From<I3>>::from(I3::SCALING_FACTOR);
+        let min = Rational::from(Decimal::<I3, D3>::MIN);
+        let max = Rational::from(Decimal::<I3, D3>::MAX);
+        let expected = |exact: Rational, mode| {
+            let rounded =
+                <Rational as From<Integer>>::from(reference_round(&(exact * &scale), mode));
+            let rounded = rounded / &scale;
+
+            (min <= rounded && rounded <= max).then_some(rounded)
+        };
+
+        proptest!(|(lhs: Decimal<I1, D1>, rhs: Decimal<I2, D2>, mode: RoundingMode)| {
+            let (lhs_r, rhs_r) = (Rational::from(lhs), Rational::from(rhs));
+
+            let out = Decimal::<I3, D3>::mul_into(lhs, rhs, mode).ok().map(Rational::from);
+            assert_eq!(out, expected(&lhs_r * &rhs_r, mode));
+
+            let out = Decimal::<I3, D3>::add_into(lhs, rhs, mode).ok().map(Rational::from);
+            assert_eq!(out, expected(&lhs_r + &rhs_r, mode));
+
+            let out = Decimal::<I3, D3>::div_into(lhs, rhs, mode).ok().map(Rational::from);
+            let reference = (rhs_r != 0).then(|| expected(&lhs_r / &rhs_r, mode)).flatten();
+            assert_eq!(out, reference);
+        });
+    }
+}
@@ -0,0 +1,411 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use num_traits::PrimInt;
+use ruint::aliases::U512;
+use thiserror::Error;
+
+use crate::full_mul_div::{mixed_mul_div, scale_round_into, sign_magnitude};
+use crate::{Decimal, DecimalError, Operation, ParseDecimalError, RoundingMode, ScaledInteger};
+
+/// A rate quoted in basis points, i.e. `Bps(x)` is the fraction `x / 10_000`.
+///
+/// `D` sets the precision of the quote itself, so `Bps<i64>` holds whole
+/// basis points while `Bps<i64, 1>` can hold `12.5bps`.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_decimal::{Bps, Decimal, RoundingMode};
+///
+/// let fee = "12.5bps".parse::<Bps<i64, 1>>().unwrap();
+/// let notional = "1000".parse::<Decimal<i64, 2>>().unwrap();
+///
+/// assert_eq!(fee.apply_to(notional, RoundingMode::HalfEven), Ok("1.25".parse().unwrap()));
+/// assert_eq!(Decimal::<i64, 5>::from(fee), "0.00125".parse().unwrap());
+/// ```
+///
+/// Converting into a [`Decimal`] whose scale is not `D + 4` does not compile:
+///
+/// ```rust,compile_fail
+/// use const_decimal::{Bps, Decimal};
+///
+/// let _ = Decimal::<i64, 4>::from(Bps::<i64, 1>(Decimal(125)));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Bps<I, const D: u8 = 0>(pub Decimal<I, D>);
+
+/// A rate quoted in percent, i.e. `Percent(x)` is the fraction `x / 100`.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_decimal::{Decimal, Percent, RoundingMode};
+///
+/// let old = "80".parse::<Decimal<i64, 2>>().unwrap();
+/// let new = "82.6".parse::<Decimal<i64, 2>>().unwrap();
+///
+/// let change = Percent::<i64, 2>::pct_change(old, new, RoundingMode::HalfEven).unwrap();
+/// assert_eq!(change.to_string(), "3.25%");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Percent<I, const D: u8>(pub Decimal<I, D>);
+
+/// Error returned when parsing a [`Bps`] or [`Percent`] fails.
+#[derive(Debug, PartialEq, Eq, Error)]
+pub enum ParseRateError<I>
+where
+    I: Display,
+{
+    #[error("Missing suffix; expected={0}")]
+    MissingSuffix(&'static str),
+    #[error("Failed to parse decimal; err={0}")]
+    ParseDecimal(#[from] ParseDecimalError<I>),
+}
+
+/// Computes `lhs - rhs` as a sign & magnitude that cannot overflow.
+fn signed_sub<I>(lhs: I, rhs: I) -> (bool, U512)
+where
+    I: PrimInt,
+{
+    let (lhs_negative, lhs) = sign_magnitude(lhs);
+    let (rhs_negative, rhs) = sign_magnitude(rhs);
+    let (lhs, rhs) = (U512::from(lhs), U512::from(rhs));
+
+    match (lhs_negative == rhs_negative, lhs >= rhs) {
+        (true, true) => (lhs_negative, lhs - rhs),
+        (true, false) => (!lhs_negative, rhs - lhs),
+        (false, _) => (lhs_negative, lhs + rhs),
+    }
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn of_out_of_range(rate: impl Display, amount: impl Display) -> ! {
+    panic!("`Decimal` mul out of range; lhs={amount}; rhs={rate}")
+}
+
+/// Implements the shared API of a rate worth `x / 10^$exponent`.
+macro_rules! impl_rate {
+    ($rate:ident, $exponent:literal, $suffix:literal) => {
+        impl<I, const D: u8> $rate<I, D>
+        where
+            I: ScaledInteger<D>,
+        {
+            /// Multiplies `amount` by this rate, rounding once according to
+            /// `mode`.
+            ///
+            /// The product is computed at full width, so only the final result
+            /// needs to fit `I`.
+            pub fn apply_to<const DA: u8>(
+                self,
+                amount: Decimal<I, DA>,
+                mode: RoundingMode,
+            ) -> Result<Decimal<I, DA>, DecimalError<I>>
+            where
+                I: ScaledInteger<DA>,
+            {
+                let exponent = -(i32::from(D) + $exponent);
+
+                mixed_mul_div(amount.0, self.0.0, 1u8, exponent, mode)
+                    .map(Decimal)
+                    .ok_or(DecimalError::OutOfRange {
+                        op: Operation::Mul,
+                        lhs: amount.0,
+                        rhs: self.0.0,
+                    })
+            }
+
+            /// Multiplies `amount` by this rate, rounding toward zero like
+            /// [`Mul`](std::ops::Mul).
+            ///
+            /// # Panics
+            ///
+            /// Panics if the result does not fit `I`.
+            #[must_use]
+            #[track_caller]
+            pub fn of<const DA: u8>(self, amount: Decimal<I, DA>) -> Decimal<I, DA>
+            where
+                I: ScaledInteger<DA>,
+            {
+                match self.apply_to(amount, RoundingMode::TowardZero) {
+                    Ok(out) => out,
+                    Err(_) => of_out_of_range(self, amount),
+                }
+            }
+
+            /// Returns the change from `old` to `new` relative to `old`, i.e.
+            /// `(new - old) / old`, rounded once according to `mode`.
+            ///
+            /// Returns an error if `old` is zero or the change does not fit
+            /// `Self`.
+            pub fn pct_change<const DA: u8>(
+                old: Decimal<I, DA>,
+                new: Decimal<I, DA>,
+                mode: RoundingMode,
+            ) -> Result<Self, DecimalError<I>>
+            where
+                I: ScaledInteger<DA>,
+            {
+                if old.0 == I::ZERO {
+                    return Err(DecimalError::DivisionByZero {
+                        op: Operation::Div,
+                        lhs: new.0,
+                        rhs: old.0,
+                    });
+                }
+
+                let (negative, change) = signed_sub(new.0, old.0);
+                let (old_negative, old_magnitude) = sign_magnitude(old.0);
+
+                scale_round_into(
+                    negative ^ old_negative,
+                    change,
+                    U512::from(old_magnitude),
+                    i32::from(D) + $exponent,
+                    mode,
+                )
+                .map(|out| $rate(Decimal(out)))
+                .ok_or(DecimalError::OutOfRange {
+                    op: Operation::Div,
+                    lhs: new.0,
+                    rhs: old.0,
+                })
+            }
+
+            /// Returns the symmetric relative difference `|lhs - rhs| /
+            /// max(|lhs|, |rhs|)`, rounded once according to `mode`.
+            ///
+            /// The difference between two zeros is zero. Returns an error if
+            /// the result does not fit `Self`.
+            pub fn relative_diff<const DA: u8>(
+                lhs: Decimal<I, DA>,
+                rhs: Decimal<I, DA>,
+                mode: RoundingMode,
+            ) -> Result<Self, DecimalError<I>>
+            where
+                I: ScaledInteger<DA>,
+            {
+                let (_, diff) = signed_sub(lhs.0, rhs.0);
+                let base = std::cmp::max(sign_magnitude(lhs.0).1, sign_magnitude(rhs.0).1);
+                if base == 0 {
+                    return Ok($rate(Decimal::ZERO));
+                }
+
+                scale_round_into(false, diff, U512::from(base), i32::from(D) + $exponent, mode)
+                    .map(|out| $rate(Decimal(out)))
+                    .ok_or(DecimalError::OutOfRange { op: Operation::Div, lhs: lhs.0, rhs: rhs.0 })
+            }
+        }
+
+        impl<I, const D: u8> Display for $rate<I, D>
+        where
+            I: ScaledInteger<D>,
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, concat!("{}", $suffix), self.0)
+            }
+        }
+
+        impl<I, const D: u8> FromStr for $rate<I, D>
+        where
+            I: ScaledInteger<D>,
+        {
+            type Err = ParseRateError<I>;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                s.strip_suffix($suffix)
+                    .ok_or(ParseRateError::MissingSuffix($suffix))?
+                    .parse()
+                    .map($rate)
+                    .map_err(ParseRateError::ParseDecimal)
+            }
+        }
+    };
+}
+
+/// Implements `From<$rate<I, D>>` for the [`Decimal`] holding the same
+/// fraction, i.e. with a scale of `D + $exponent`, so any other scale is a type
+/// error.
+macro_rules! impl_into_fraction {
+    ($rate:ident; $($scale:literal => $fraction:literal),+ $(,)?) => {
+        $(
+            impl<I> From<$rate<I, $scale>> for Decimal<I, $fraction> {
+                #[inline]
+                fn from(rate: $rate<I, $scale>) -> Self {
+                    Decimal(rate.0.0)
+                }
+            }
+        )+
+    };
+}
+
+impl_rate!(Bps, 4, "bps");
+impl_rate!(Percent, 2, "%");
+
+impl_into_fraction!(Bps;
+    0 => 4, 1 => 5, 2 => 6, 3 => 7, 4 => 8, 5 => 9, 6 => 10, 7 => 11, 8 => 12, 9 => 13,
+    10 => 14, 11 => 15, 12 => 16, 13 => 17, 14 => 18, 15 => 19, 16 => 20, 17 => 21,
+    18 => 22, 19 => 23, 20 => 24, 21 => 25, 22 => 26, 23 => 27, 24 => 28, 25 => 29,
+    26 => 30, 27 => 31, 28 => 32, 29 => 33, 30 => 34, 31 => 35, 32 => 36, 33 => 37,
+    34 => 38,
+);
+impl_into_fraction!(Percent;
+    0 => 2, 1 => 3, 2 => 4, 3 => 5, 4 => 6, 5 => 7, 6 => 8, 7 => 9, 8 => 10, 9 => 11,
+    10 => 12, 11 => 13, 12 => 14, 13 => 15, 14 => 16, 15 => 17, 16 => 18, 17 => 19,
+    18 => 20, 19 => 21, 20 => 22, 21 => 23, 22 => 24, 23 => 25, 24 => 26, 25 => 27,
+    26 => 28, 27 => 29, 28 => 30, 29 => 31, 30 => 32, 31 => 33, 32 => 34, 33 => 35,
+    34 => 36, 35 => 37, 36 => 38,
+);
+
+#[cfg(test)]
+mod tests {
+    use malachite::base::num::arithmetic::traits::Pow;
+    use malachite::{Integer, Rational};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::macros::generate_tests_for_common_variants;
+    use crate::rounding::reference_round;
+
+    #[test]
+    fn display_from_str() {
+        assert_eq!("12.5bps".parse::<Bps<i64, 1>>(), Ok(Bps(Decimal(125))));
+        assert_eq!("-3.25%".parse::<Percent<i32, 2>>(), Ok(Percent(Decimal(-325))));
+        assert_eq!("7bps".parse::<Bps<u64>>(), Ok(Bps(Decimal(7))));
+        assert_eq!(Bps::<i64, 1>(Decimal(125)).to_string(), "12.5bps");
+        assert_eq!(Percent::<u64, 2>(Decimal(325)).to_string(), "3.25%");
+
+        assert_eq!("12.5".parse::<Bps<i64, 1>>(), Err(ParseRateError::MissingSuffix("bps")));
+        assert_eq!("3.25bps".parse::<Percent<i64, 2>>(), Err(ParseRateError::MissingSuffix("%")));
+        assert_eq!(
+            "%".parse::<Percent<i64, 2>>(),
+            Err(ParseRateError::ParseDecimal(ParseDecimalError::EmptyString))
+        );
+        assert_eq!(
+            "1.255%".parse::<Percent<i64, 2>>(),
+            Err(ParseRateError::ParseDecimal(ParseDecimalError::PrecisionLoss(3)))
+        );
+    }
+
+    #[test]
+    fn into_fraction() {
+        assert_eq!(Decimal::<u64, 4>::from(Bps::<u64>(Decimal(25))), "0.0025".parse().unwrap());
+        assert_eq!(Decimal::<i64, 4>::from(Percent::<i64, 2>(Decimal(-325))), Decimal(-325));
+    }
+
+    #[test]
+    fn apply_to() {
+        let amount = "0.10".parse::<Decimal<i64, 2>>().unwrap();
+        let fee = Bps::<i64>(Decimal(5_000));
+        assert_eq!(fee.apply_to(amount, RoundingMode::HalfEven), Ok(Decimal(5)));
+        assert_eq!(fee.apply_to(-amount, RoundingMode::Floor), Ok(Decimal(-5)));
+
+        // 2.5% of 0.10 is 0.0025.
+        let fee = Percent::<i64, 1>(Decimal(25));
+        assert_eq!(fee.apply_to(amount, RoundingMode::HalfEven), Ok(Decimal(0)));
+        assert_eq!(fee.apply_to(amount, RoundingMode::Ceiling), Ok(Decimal(1)));
+        assert_eq!(fee.of(-amount), Decimal(0));
+
+        // The intermediate product overflows `u64` but the result does not.
+        let amount = Decimal::<u64, 0>::MAX;
+        let half = Percent::<u64, 2>(Decimal(5_000));
+        assert_eq!(half.of(amount), Decimal(u64::MAX / 2));
+        assert_eq!(
+            Percent::<u64, 0>(Decimal(101)).apply_to(amount, RoundingMode::HalfEven),
+            Err(DecimalError::OutOfRange { op: Operation::Mul, lhs: u64::MAX, rhs: 101 }),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`Decimal` mul out of range; lhs=255.0; rhs=200.0%")]
+    fn of_panics_out_of_range() {
+        let _ = Percent::<u8, 0>(Decimal(200)).of(Decimal::<u8, 0>(255));
+    }
+
+    #[test]
+    fn pct_change() {
+        let old = Decimal::<i8, 0>(-128);
+        let new = Decimal::<i8, 0>(127);
+        assert_eq!(
+            Bps::<i64, 0>::pct_change(Decimal::<i64, 2>(4), Decimal(5), RoundingMode::HalfEven)
+                .ok(),
+            Some(Bps(Decimal(2_500))),
+        );
+        assert_eq!(
+            Percent::<i8, 0>::pct_change(old, new, RoundingMode::HalfEven),
+            Err(DecimalError::OutOfRange { op: Operation::Div, lhs: 127, rhs: -128 }),
+        );
+        assert_eq!(
+            Percent::<i8, 0>::pct_change(new, old, RoundingMode::HalfEven),
+            Err(DecimalError::OutOfRange { op: Operation::Div, lhs: -128, rhs: 127 }),
+        );
+        assert_eq!(
+            Percent::<i8, 0>::pct_change(Decimal::<i8, 1>(-4), Decimal(-5), RoundingMode::HalfEven),
+            Ok(Percent(Decimal(25))),
+        );
+        assert_eq!(
+            Percent::<i8, 0>::pct_change(Decimal::<i8, 1>(0), Decimal(-5), RoundingMode::HalfEven),
+            Err(DecimalError::DivisionByZero { op: Operation::Div, lhs: -5, rhs: 0 }),
+        );
+    }
+
+    #[test]
+    fn relative_diff() {
+        assert_eq!(
+            Percent::<u8, 0>::relative_diff(Decimal::<u8, 1>(0), Decimal(0), RoundingMode::Ceiling),
+            Ok(Percent(Decimal(0))),
+        );
+        assert_eq!(
+            Percent::<i16, 1>::relative_diff(
+                Decimal::<i16, 0>(-3),
+                Decimal(6),
+                RoundingMode::Floor
+            ),
+            Ok(Percent(Decimal(1_500))),
+        );
+        assert_eq!(
+            Bps::<u32>::relative_diff(Decimal::<u32, 2>(300), Decimal(200), RoundingMode::Floor),
+            Ok(Bps(Decimal(3_333))),
+        );
+    }
+
+    fn differential_fuzz_rate<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        let narrow = |x: &Integer| {
+            (I::min_value().into() <= *x && *x <= I::max_value().into()).then_some(x.clone())
+        };
+        proptest!(|(rate: Decimal<I, D>, lhs: Decimal<I, D>, rhs: Decimal<I, D>, mode: RoundingMode)| {
+            let rate_scale = Rational::from_unsigneds(10u8, 1).pow(u64::from(D) + 2);
+            let raw = |x: I| <Rational as From<Integer>>::from(x.into());
+
+            // Applying `rate` percent to `lhs`.
+            let exact = raw(lhs.0) * raw(rate.0) / &rate_scale;
+            let reference = narrow(&reference_round(&exact, mode));
+            let out = Percent(rate).apply_to(lhs, mode);
+            assert_eq!(out.ok().map(|out| out.0.into()), reference);
+
+            // Percent change from `lhs` to `rhs`.
+            let out = Percent::<I, D>::pct_change(lhs, rhs, mode);
+            match lhs.0 == I::ZERO {
+                true => assert!(matches!(out, Err(DecimalError::DivisionByZero { .. }))),
+                false => {
+                    let exact = (raw(rhs.0) - raw(lhs.0)) / raw(lhs.0) * &rate_scale;
+                    let reference = narrow(&reference_round(&exact, mode));
+                    assert_eq!(out.ok().map(|out| out.0.0.into()), reference);
+                }
+            }
+        });
+    }
+
+    generate_tests_for_common_variants!(differential_fuzz_rate);
+}
@@ -0,0 +1,368 @@
+use std::cmp::{Ordering, max};
+
+use ruint::Uint;
+use ruint::aliases::{U256, U512};
+
+use crate::full_mul_div::{from_sign_magnitude, sign_magnitude};
+use crate::{Decimal, DecimalError, Operation, RoundingMode, ScaledInteger};
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+fn sqrt_undefined<I, const D: u8>(value: Decimal<I, D>) -> !
+where
+    I: ScaledInteger<D>,
+{
+    panic!("`Decimal` sqrt is undefined; value={value}")
+}
+
+/// Computes the `degree`-th root of `radicand`, rounding according to `mode`
+/// given the sign of the result.
+///
+/// Returns `None` if the rounding check does not fit the radicand's width.
+fn round_root<const BITS: usize, const LIMBS: usize>(
+    radicand: Uint<BITS, LIMBS>,
+    degree: usize,
+    negative: bool,
+    mode: RoundingMode,
+) -> Option<Uint<BITS, LIMBS>> {
+    let floor = radicand.root(degree);
+    let exponent = Uint::from(degree);
+    if floor.checked_pow(exponent)? == radicand {
+        return Some(floor);
+    }
+
+    // The exact root lies strictly between `floor` and `floor + 1`. Compare it
+    // to the midpoint by scaling both sides by `2^degree`; a tie is impossible
+    // as `(2 * floor + 1)^degree` is odd.
+    let midpoint = floor
+        .checked_mul(Uint::from(2))?
+        .checked_add(Uint::ONE)?
+        .checked_pow(exponent)?;
+    let half = radicand.checked_shl(degree)?.cmp(&midpoint);
+
+    match mode.rounds_away(negative, floor.bit(0), half) {
+        true => floor.checked_add(Uint::ONE),
+        false => Some(floor),
+    }
+}
+
+/// Computes the `n`-th root of `magnitude * scaling^(n - 1)` in a
+/// `Uint<BITS, LIMBS>`, rounding according to `mode` given the sign of the
+/// result.
+fn root_at_width<const BITS: usize, const LIMBS: usize>(
+    magnitude: u128,
+    scaling: u128,
+    n: u32,
+    negative: bool,
+    mode: RoundingMode,
+) -> Option<u128> {
+    let radicand = Uint::<BITS, LIMBS>::from(scaling)
+        .checked_pow(Uint::from(n - 1))?
+        .checked_mul(Uint::from(magnitude))?;
+
+    round_root(radicand, n as usize, negative, mode).and_then(|root| u128::try_from(root).ok())
+}
+
+/// Rounds the `n`-th root of an unscaled `magnitude` in `[1, 2^n)`, i.e. a
+/// root with a floor of one, according to `mode`.
+fn round_unit_root(magnitude: u128, n: u32, negative: bool, mode: RoundingMode) -> u128 {
+    if magnitude == 1 {
+        return 1;
+    }
+
+    // Compare the root to the midpoint `1.5` by comparing `magnitude` to
+    // `1.5^n`; beyond `n = 218` the latter exceeds every `u128`.
+    let half = match n {
+        ..=218 => (U512::from(magnitude) << n as usize).cmp(&U512::from(3).pow(U512::from(n))),
+        _ => Ordering::Less,
+    };
+
+    match mode.rounds_away(negative, true, half) {
+        true => 2,
+        false => 1,
+    }
+}
+
+impl<I, const D: u8> Decimal<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    /// Computes the square root, rounded according to `mode`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let two = "2".parse::<Decimal<u64, 9>>().unwrap();
+    /// assert_eq!(two.sqrt(RoundingMode::Floor), "1.414213562".parse().unwrap());
+    /// assert_eq!(two.sqrt(RoundingMode::Ceiling), "1.414213563".parse().unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is negative.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn sqrt(self, mode: RoundingMode) -> Self {
+        match self.checked_sqrt(mode) {
+            Ok(out) => out,
+            Err(_) => sqrt_undefined(self),
+        }
+    }
+
+    /// Computes the square root, rounded according to `mode`, returning an
+    /// error if `self` is negative.
+    ///
+    /// The root of `self.0 * SCALING_FACTOR` is computed exactly in a `U256`,
+    /// so the result is correctly rounded for every backing.
+    pub fn checked_sqrt(self, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        let (negative, magnitude) = sign_magnitude(self.0);
+        if negative {
+            return Err(DecimalError::Undefined { op: Operation::Sqrt, value: self.0 });
+        }
+
+        let (_, scaling) = sign_magnitude(I::SCALING_FACTOR);
+        let radicand = U256::from(magnitude)
+            .checked_mul(U256::from(scaling))
+            .expect("two u128 always fit U256");
+
+        // Unlike `round_root` this never leaves 256 bits: the discarded
+        // fraction exceeds one half iff `radicand - floor^2 > floor`.
+        let floor = radicand.root(2);
+        let remainder = radicand - floor * floor;
+        let half = match remainder > floor {
+            true => Ordering::Greater,
+            false => Ordering::Less,
+        };
+        let root = match !remainder.is_zero() && mode.rounds_away(false, floor.bit(0), half) {
+            true => floor + U256::ONE,
+            false => floor,
+        };
+
+        // The root is at most `max(self, SCALING_FACTOR)`, so it always fits.
+        u128::try_from(root)
+            .ok()
+            .and_then(|root| from_sign_magnitude(false, root))
+            .map(Decimal)
+            .ok_or(DecimalError::UnaryOverflow { op: Operation::Sqrt, value: self.0 })
+    }
+
+    /// Computes the `n`-th root, rounded according to `mode`.
+    ///
+    /// The root of `|self.0| * SCALING_FACTOR^(n - 1)` is computed exactly, in
+    /// an integer sized from `n` & the bit length of the operands, so the
+    /// result is correctly rounded.
+    ///
+    /// Returns an error if `n` is zero, if `n` is even and `self` is negative,
+    /// or if the exact computation would need more than `2^17` bits, which
+    /// takes a degree of a thousand or more for a non-zero scale.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use const_decimal::{Decimal, RoundingMode};
+    ///
+    /// let x = "-27.000001".parse::<Decimal<i64, 6>>().unwrap();
+    /// assert_eq!(x.nth_root(3, RoundingMode::HalfEven), Ok("-3".parse().unwrap()));
+    /// assert_eq!(x.nth_root(3, RoundingMode::Floor), Ok("-3.000001".parse().unwrap()));
+    /// ```
+    pub fn nth_root(self, n: u32, mode: RoundingMode) -> Result<Self, DecimalError<I>> {
+        let (negative, magnitude) = sign_magnitude(self.0);
+        if n == 0 || (negative && n.is_multiple_of(2)) {
+            return Err(DecimalError::Undefined { op: Operation::Root, value: self.0 });
+        }
+
+        let (_, scaling) = sign_magnitude(I::SCALING_FACTOR);
+        if magnitude == 0 {
+            return Ok(Self::ZERO);
+        }
+
+        // NB: The root never exceeds `max(magnitude, scaling)`, so the radicand,
+        // the midpoint `(2 * floor + 1)^n` & the radicand scaled by `2^n` all fit
+        // `n * (bits + 1)` bits.
+        let bits = u64::from(u128::BITS - max(magnitude, scaling).leading_zeros());
+        let root = match u64::from(n).saturating_mul(bits.saturating_add(1)) {
+            // `1 <= root < 2` only happens without a scale & rounds without
+            // powers that grow with `n`.
+            _ if scaling == 1 && (n >= u128::BITS || magnitude < 1 << n) => {
+                Some(round_unit_root(magnitude, n, negative, mode))
+            }
+            ..=2048 => root_at_width::<2048, 32>(magnitude, scaling, n, negative, mode),
+            2049..=16384 => root_at_width::<16384, 256>(magnitude, scaling, n, negative, mode),
+            16385..=131_072 => {
+                root_at_width::<131_072, 2048>(magnitude, scaling, n, negative, mode)
+            }
+            _ => None,
+        };
+
+        root.and_then(|root| from_sign_magnitude(negative, root))
+            .map(Decimal)
+            .ok_or(DecimalError::UnaryOverflow { op: Operation::Root, value: self.0 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::base::num::arithmetic::traits::{FloorRoot, Pow};
+    use malachite::{Integer, Rational};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::macros::generate_tests_for_common_variants;
+    use crate::rounding::reference_round;
+
+    #[test]
+    fn sqrt_edges() {
+        let zero = Decimal::<u8, 2>::ZERO;
+        assert_eq!(zero.sqrt(RoundingMode::Ceiling), zero);
+        assert_eq!(Decimal::<u8, 2>::MAX.sqrt(RoundingMode::HalfEven), Decimal(160));
+        assert_eq!(
+            Decimal::<i128, 18>::MAX
+                .checked_sqrt(RoundingMode::Ceiling)
+                .map(|x| x.0),
+            Ok(13_043_817_825_332_782_212_349_571_807),
+        );
+        assert_eq!(
+            Decimal::<i64, 9>(-1).checked_sqrt(RoundingMode::HalfEven),
+            Err(DecimalError::Undefined { op: Operation::Sqrt, value: -1 }),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`Decimal` sqrt is undefined; value=-0.000000001")]
+    fn sqrt_negative_panics() {
+        let _ = Decimal::<i64, 9>(-1).sqrt(RoundingMode::HalfEven);
+    }
+
+    #[test]
+    fn nth_root_edges() {
+        let x = "-8".parse::<Decimal<i64, 9>>().unwrap();
+
+        assert_eq!(x.nth_root(3, RoundingMode::TowardZero), Ok("-2".parse().unwrap()));
+        assert_eq!(x.nth_root(1, RoundingMode::TowardZero), Ok(x));
+        assert_eq!(
+            x.nth_root(0, RoundingMode::TowardZero),
+            Err(DecimalError::Undefined { op: Operation::Root, value: x.0 }),
+        );
+        assert_eq!(
+            x.nth_root(2, RoundingMode::TowardZero),
+            Err(DecimalError::Undefined { op: Operation::Root, value: x.0 }),
+        );
+    }
+
+    #[test]
+    fn nth_root_large_degree() {
+        let two = Decimal::<i64, 0>(2);
+        assert_eq!(two.nth_root(10_000, RoundingMode::HalfEven), Ok(Decimal(1)));
+        assert_eq!(two.nth_root(10_000, RoundingMode::Ceiling), Ok(Decimal(2)));
+        assert_eq!(Decimal::<i64, 0>(-3).nth_root(u32::MAX, RoundingMode::Floor), Ok(Decimal(-2)));
+        assert_eq!(Decimal::<u8, 0>(2).nth_root(1, RoundingMode::Floor), Ok(Decimal(2)));
+
+        // 2^(1/80) = 1.00870198379...; 2^(1/1000) = 1.00069338746...
+        let two = "2".parse::<Decimal<i64, 9>>().unwrap();
+        assert_eq!(two.nth_root(80, RoundingMode::HalfEven), Ok("1.008701984".parse().unwrap()));
+        assert_eq!(two.nth_root(1000, RoundingMode::HalfEven), Ok("1.000693387".parse().unwrap()));
+        assert_eq!(
+            Decimal::<i128, 18>::MAX.nth_root(200, RoundingMode::TowardZero),
+            Ok("1.262275192904905104".parse().unwrap()),
+        );
+        // The widest computation, at `1000 * 129` bits.
+        assert_eq!(
+            Decimal::<i128, 18>::MAX.nth_root(1000, RoundingMode::Ceiling),
+            Ok("1.047685201216752479".parse().unwrap()),
+        );
+
+        // Beyond `2^17` bits the exact computation is refused.
+        assert_eq!(
+            two.nth_root(10_000, RoundingMode::HalfEven),
+            Err(DecimalError::UnaryOverflow { op: Operation::Root, value: two.0 }),
+        );
+    }
+
+    /// Rounds the exact `n`-th root of `radicand` (an integer) according to
+    /// `mode`, using a rational stand-in on the correct side of the midpoint.
+    fn reference_root(radicand: &Integer, n: u64, mode: RoundingMode) -> Integer {
+        let negative = *radicand < 0;
+        let magnitude = match negative {
+            true => -radicand,
+            false => radicand.clone(),
+        };
+        let floor = (&magnitude).floor_root(n);
+        if (&floor).pow(n) == magnitude {
+            return match negative {
+                true => -floor,
+                false => floor,
+            };
+        }
+
+        let midpoint =
+            Rational::from_integers(Integer::from(2) * &floor + Integer::from(1), 2.into());
+        let stand_in = match magnitude < (&midpoint).pow(n) {
+            true => Rational::from(floor) + Rational::from_signeds(1, 4),
+            false => Rational::from(floor) + Rational::from_signeds(3, 4),
+        };
+
+        reference_round(
+            &match negative {
+                true => -stand_in,
+                false => stand_in,
+            },
+            mode,
+        )
+    }
+
+    fn differential_fuzz_root<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        proptest!(|(a: Decimal<I, D>, n in 1u32..6, mode: RoundingMode)| {
+            let scaling: Integer = I::SCALING_FACTOR.into();
+            let value: Integer = a.0.into();
+
+            match a.checked_sqrt(mode) {
+                Ok(out) => {
+                    let reference = reference_root(&(&value * &scaling), 2, mode);
+                    assert_eq!(out.0.into(), reference);
+                    assert_eq!(a.nth_root(2, mode), Ok(out));
+                }
+                Err(_) => assert!(value < 0),
+            }
+
+            let radicand = value.clone() * (&scaling).pow(u64::from(n - 1));
+            match a.nth_root(n, mode) {
+                Ok(out) => assert_eq!(out.0.into(), reference_root(&radicand, u64::from(n), mode)),
+                Err(err) => {
+                    assert!(value < 0 && n.is_multiple_of(2));
+                    assert_eq!(err, DecimalError::Undefined { op: Operation::Root, value: a.0 });
+                }
+            }
+        });
+    }
+
+    generate_tests_for_common_variants!(differential_fuzz_root);
+
+    fn differential_fuzz_root_large_degree<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+    {
+        let config = ProptestConfig { cases: 32, ..ProptestConfig::default() };
+        proptest!(config, |(a: Decimal<I, D>, n in 6u32..400, mode: RoundingMode)| {
+            let scaling: Integer = I::SCALING_FACTOR.into();
+            let value: Integer = a.0.into();
+            let radicand = value.clone() * (&scaling).pow(u64::from(n - 1));
+
+            match a.nth_root(n, mode) {
+                Ok(out) => assert_eq!(out.0.into(), reference_root(&radicand, u64::from(n), mode)),
+                Err(err) => {
+                    assert!(value < 0 && n.is_multiple_of(2));
+                    assert_eq!(err, DecimalError::Undefined { op: Operation::Root, value: a.0 });
+                }
+            }
+        });
+    }
+
+    generate_tests_for_common_variants!(differential_fuzz_root_large_degree);
+}
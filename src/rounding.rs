@@ -0,0 +1,226 @@
+use std::cmp::Ordering;
+
+use num_traits::PrimInt;
+use ruint::Uint;
+
+/// Strategy used when an operation has to discard precision.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round towards zero (truncate), matching [`Mul`](std::ops::Mul) and
+    /// [`Div`](std::ops::Div).
+    #[default]
+    TowardZero,
+    /// Round away from zero.
+    AwayFromZero,
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceiling,
+    /// Round to the nearest value, with ties rounded away from zero.
+    HalfUp,
+    /// Round to the nearest value, with ties rounded towards zero.
+    HalfDown,
+    /// Round to the nearest value, with ties rounded to the even neighbour
+    /// (banker's rounding).
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Decides whether a result that was truncated towards zero should instead
+    /// be moved one unit away from zero.
+    ///
+    /// - `negative`: The sign of the exact result.
+    /// - `odd`: Whether the truncated result is odd.
+    /// - `remainder`: The discarded (non-zero) fraction compared to one half.
+    #[inline]
+    pub(crate) const fn rounds_away(self, negative: bool, odd: bool, remainder: Ordering) -> bool {
+        match self {
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            RoundingMode::Floor => negative,
+            RoundingMode::Ceiling => !negative,
+            RoundingMode::HalfUp => !remainder.is_lt(),
+            RoundingMode::HalfDown => remainder.is_gt(),
+            RoundingMode::HalfEven => remainder.is_gt() || (remainder.is_eq() && odd),
+        }
+    }
+
+    /// The mode rounding in the opposite direction, e.g. for an operand of a
+    /// decreasing function such as a reciprocal.
+    #[inline]
+    pub(crate) const fn reversed(self) -> RoundingMode {
+        match self {
+            RoundingMode::TowardZero => RoundingMode::AwayFromZero,
+            RoundingMode::AwayFromZero => RoundingMode::TowardZero,
+            RoundingMode::Floor => RoundingMode::Ceiling,
+            RoundingMode::Ceiling => RoundingMode::Floor,
+            RoundingMode::HalfUp => RoundingMode::HalfDown,
+            RoundingMode::HalfDown => RoundingMode::HalfUp,
+            RoundingMode::HalfEven => RoundingMode::HalfEven,
+        }
+    }
+}
+
+/// Computes `numer / div` rounded according to `mode`.
+///
+/// The magnitude of `div` must be representable in `W`, i.e. `div` must not be
+/// `W::MIN` for signed integers.
+#[inline]
+pub(crate) fn round_div<W>(numer: W, div: W, mode: RoundingMode) -> W
+where
+    W: PrimInt,
+{
+    let quotient = numer / div;
+    let remainder = numer % div;
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    let abs = |x: W| match x < W::zero() {
+        true => W::zero() - x,
+        false => x,
+    };
+    let negative = (numer < W::zero()) != (div < W::zero());
+    let remainder = abs(remainder);
+    let half = remainder.cmp(&(abs(div) - remainder));
+    let odd = quotient & W::one() == W::one();
+
+    match (mode.rounds_away(negative, odd, half), negative) {
+        (false, _) => quotient,
+        (true, false) => quotient + W::one(),
+        (true, true) => quotient - W::one(),
+    }
+}
+
+/// Computes `numer / div` on magnitudes, rounding according to `mode` given
+/// the sign of the exact result.
+#[inline]
+pub(crate) fn round_div_uint<const BITS: usize, const LIMBS: usize>(
+    numer: Uint<BITS, LIMBS>,
+    div: Uint<BITS, LIMBS>,
+    negative: bool,
+    mode: RoundingMode,
+) -> Uint<BITS, LIMBS> {
+    let (quotient, remainder) = numer.div_rem(div);
+    if remainder.is_zero() {
+        return quotient;
+    }
+
+    let half = remainder.cmp(&(div - remainder));
+    match mode.rounds_away(negative, quotient.bit(0), half) {
+        true => quotient + Uint::ONE,
+        false => quotient,
+    }
+}
+
+/// Rounds an exact rational to an integer, independently of the rounding
+/// logic under test.
+#[cfg(test)]
+pub(crate) fn reference_round(
+    exact: &malachite::Rational,
+    mode: RoundingMode,
+) -> malachite::Integer {
+    use malachite::base::num::arithmetic::traits::Parity;
+    use malachite::base::num::conversion::traits::RoundingFrom;
+    use malachite::base::rounding_modes::RoundingMode as MalachiteRounding;
+    use malachite::{Integer, Rational};
+
+    let floor = Integer::rounding_from(exact, MalachiteRounding::Floor).0;
+    let ceiling = Integer::rounding_from(exact, MalachiteRounding::Ceiling).0;
+    if floor == ceiling {
+        return floor;
+    }
+
+    let positive = *exact > 0;
+    let (toward_zero, away_from_zero) = match positive {
+        true => (floor.clone(), ceiling.clone()),
+        false => (ceiling.clone(), floor.clone()),
+    };
+    let nearest = (exact - Rational::from(&floor)).cmp(&(Rational::from(&ceiling) - exact));
+
+    let tie = match mode {
+        RoundingMode::TowardZero => return toward_zero,
+        RoundingMode::AwayFromZero => return away_from_zero,
+        RoundingMode::Floor => return floor,
+        RoundingMode::Ceiling => return ceiling,
+        RoundingMode::HalfUp => away_from_zero,
+        RoundingMode::HalfDown => toward_zero,
+        RoundingMode::HalfEven => match floor.even() {
+            true => floor.clone(),
+            false => ceiling.clone(),
+        },
+    };
+
+    match nearest {
+        Ordering::Less => floor,
+        Ordering::Equal => tie,
+        Ordering::Greater => ceiling,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::{Integer, Rational};
+    use proptest::prelude::*;
+    use ruint::aliases::U256;
+
+    use super::*;
+
+    #[test]
+    fn round_div_matches_reference() {
+        proptest!(|(numer: i32, div: i32, mode: RoundingMode)| {
+            if div == 0 || div == i32::MIN {
+                return Ok(());
+            }
+
+            let exact = Rational::from_integers(Integer::from(numer), Integer::from(div));
+            let reference = reference_round(&exact, mode);
+
+            assert_eq!(Integer::from(round_div(i64::from(numer), i64::from(div), mode)), reference);
+        });
+    }
+
+    #[test]
+    fn round_div_uint_matches_reference() {
+        proptest!(|(numer: u128, div: u128, negative: bool, mode: RoundingMode)| {
+            if div == 0 {
+                return Ok(());
+            }
+
+            let sign = match negative {
+                true => Integer::from(-1),
+                false => Integer::from(1),
+            };
+            let exact = Rational::from_integers(Integer::from(numer) * &sign, Integer::from(div));
+            let reference = reference_round(&exact, mode);
+
+            let out = round_div_uint(U256::from(numer), U256::from(div), negative, mode);
+            assert_eq!(Integer::from(u128::try_from(out).unwrap()) * sign, reference);
+        });
+    }
+
+    #[test]
+    fn half_modes_on_ties() {
+        let cases = [
+            (5, 2, [2, 3, 2, 3, 3, 2, 2]),
+            (7, 2, [3, 4, 3, 4, 4, 3, 4]),
+            (-5, 2, [-2, -3, -3, -2, -3, -2, -2]),
+            (-7, 2, [-3, -4, -4, -3, -4, -3, -4]),
+        ];
+        let modes = [
+            RoundingMode::TowardZero,
+            RoundingMode::AwayFromZero,
+            RoundingMode::Floor,
+            RoundingMode::Ceiling,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+        ];
+
+        for (numer, div, expected) in cases {
+            for (mode, expected) in modes.into_iter().zip(expected) {
+                assert_eq!(round_div(numer, div, mode), expected, "{numer}/{div} {mode:?}");
+            }
+        }
+    }
+}
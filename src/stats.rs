@@ -0,0 +1,692 @@
+//! Every statistic is computed from exact wide sums and rounded once
+//! according to the given [`RoundingMode`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use const_decimal::stats::{self, Interpolation};
+//! use const_decimal::{Decimal, RoundingMode};
+//!
+//! let prices: Vec<Decimal<u64, 2>> =
+//!     ["101.00", "102.00", "100.50"].iter().map(|p| p.parse().unwrap()).collect();
+//! let volumes: Vec<Decimal<u64, 2>> =
+//!     ["10", "30", "20"].iter().map(|v| v.parse().unwrap()).collect();
+//!
+//! let vwap = stats::weighted_mean(&prices, &volumes, RoundingMode::HalfEven).unwrap();
+//! assert_eq!(vwap, "101.33".parse().unwrap());
+//!
+//! let median = stats::median(&prices, RoundingMode::HalfEven).unwrap();
+//! assert_eq!(median, "101.00".parse().unwrap());
+//!
+//! let p25 = Decimal::<u8, 0>(25);
+//! let out = stats::percentile(&prices, p25, Interpolation::Linear, RoundingMode::HalfEven);
+//! assert_eq!(out, Ok("100.75".parse().unwrap()));
+//! ```
+
+use std::cmp::Ordering;
+
+use ruint::aliases::{U256, U512, U1024};
+use thiserror::Error;
+
+use crate::full_mul_div::{from_sign_magnitude, sign_magnitude};
+use crate::rounding::round_div_uint;
+use crate::{Decimal, RoundingMode, ScaledInteger};
+
+/// Error returned when a statistic cannot be computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum StatsError {
+    #[error("Statistic is undefined for empty input")]
+    Empty,
+    #[error("Statistic does not fit the backing integer")]
+    Overflow,
+    #[error("Values & weights differ in length")]
+    LengthMismatch,
+    #[error("Weights sum to zero")]
+    ZeroWeight,
+    #[error("Percentile must be within [0, 100]")]
+    InvalidPercentile,
+}
+
+/// How [`percentile`] picks a value when the requested rank falls between two
+/// samples.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Interpolate linearly between the two neighbouring samples.
+    #[default]
+    Linear,
+    /// Take the lower neighbouring sample.
+    Lower,
+    /// Take the higher neighbouring sample.
+    Higher,
+    /// Take the nearest neighbouring sample, with ties going to the even rank.
+    Nearest,
+    /// Take the mean of the two neighbouring samples.
+    Midpoint,
+}
+
+/// Exact signed sum kept as separate positive & negative magnitudes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct SignedSum {
+    positive: U512,
+    negative: U512,
+}
+
+impl SignedSum {
+    const ZERO: SignedSum = SignedSum { positive: U512::ZERO, negative: U512::ZERO };
+
+    fn checked_add(self, negative: bool, magnitude: U512) -> Option<Self> {
+        match negative {
+            true => Some(SignedSum { negative: self.negative.checked_add(magnitude)?, ..self }),
+            false => Some(SignedSum { positive: self.positive.checked_add(magnitude)?, ..self }),
+        }
+    }
+
+    fn checked_merge(self, other: Self) -> Option<Self> {
+        self.checked_add(false, other.positive)?
+            .checked_add(true, other.negative)
+    }
+
+    fn sign_magnitude(self) -> (bool, U512) {
+        match self.positive >= self.negative {
+            true => (false, self.positive - self.negative),
+            false => (true, self.negative - self.positive),
+        }
+    }
+}
+
+/// Online mean, variance, standard deviation, minimum & maximum.
+///
+/// Values are folded into exact wide sums so pushing never rounds and
+/// [`merge`](RunningStats::merge) is exact, making it suitable for parallel
+/// reductions.
+///
+/// # Examples
+///
+/// ```rust
+/// use const_decimal::stats::RunningStats;
+/// use const_decimal::{Decimal, RoundingMode};
+///
+/// let mut stats = RunningStats::<i64, 2>::new();
+/// stats.extend([2, 4, 4, 4, 5, 5, 7, 9].map(|x| Decimal::try_from_scaled(x, 0).unwrap()));
+///
+/// assert_eq!(stats.mean(RoundingMode::HalfEven), Ok("5".parse().unwrap()));
+/// assert_eq!(stats.variance(RoundingMode::HalfEven), Ok("4".parse().unwrap()));
+/// assert_eq!(stats.stddev(RoundingMode::HalfEven), Ok("2".parse().unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RunningStats<I, const D: u8> {
+    count: u64,
+    sum: SignedSum,
+    sum_squares: U512,
+    min: Option<Decimal<I, D>>,
+    max: Option<Decimal<I, D>>,
+    overflowed: bool,
+}
+
+impl<I, const D: u8> RunningStats<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    #[must_use]
+    pub const fn new() -> Self {
+        RunningStats {
+            count: 0,
+            sum: SignedSum::ZERO,
+            sum_squares: U512::ZERO,
+            min: None,
+            max: None,
+            overflowed: false,
+        }
+    }
+
+    /// Adds a single value to the statistics.
+    pub fn push(&mut self, value: Decimal<I, D>) {
+        let (negative, magnitude) = sign_magnitude(value.0);
+        let magnitude = U512::from(magnitude);
+
+        let mut other = Self::new();
+        other.count = 1;
+        other.sum = SignedSum::ZERO
+            .checked_add(negative, magnitude)
+            .expect("a single magnitude always fits");
+        other.sum_squares = magnitude * magnitude;
+        other.min = Some(value);
+        other.max = Some(value);
+
+        self.merge(other);
+    }
+
+    /// Combines the statistics of two disjoint sets of values.
+    pub fn merge(&mut self, other: Self) {
+        self.min = match (self.min, other.min) {
+            (Some(lhs), Some(rhs)) => Some(std::cmp::min(lhs, rhs)),
+            (lhs, rhs) => lhs.or(rhs),
+        };
+        self.max = match (self.max, other.max) {
+            (Some(lhs), Some(rhs)) => Some(std::cmp::max(lhs, rhs)),
+            (lhs, rhs) => lhs.or(rhs),
+        };
+
+        let merged = (
+            self.count.checked_add(other.count),
+            self.sum.checked_merge(other.sum),
+            self.sum_squares.checked_add(other.sum_squares),
+        );
+        match merged {
+            (Some(count), Some(sum), Some(sum_squares)) => {
+                self.count = count;
+                self.sum = sum;
+                self.sum_squares = sum_squares;
+            }
+            _ => self.overflowed = true,
+        }
+        self.overflowed |= other.overflowed;
+    }
+
+    /// The number of values pushed.
+    #[must_use]
+    pub const fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// The smallest value pushed.
+    pub fn min(&self) -> Result<Decimal<I, D>, StatsError> {
+        self.min.ok_or(StatsError::Empty)
+    }
+
+    /// The largest value pushed.
+    pub fn max(&self) -> Result<Decimal<I, D>, StatsError> {
+        self.max.ok_or(StatsError::Empty)
+    }
+
+    /// The arithmetic mean, rounded according to `mode`.
+    pub fn mean(&self, mode: RoundingMode) -> Result<Decimal<I, D>, StatsError> {
+        let count = self.checked_count()?;
+        let (negative, magnitude) = self.sum.sign_magnitude();
+
+        narrow(negative, round_div_uint(magnitude, U512::from(count), negative, mode))
+    }
+
+    /// The population variance (i.e. dividing by the number of values),
+    /// rounded according to `mode`.
+    pub fn variance(&self, mode: RoundingMode) -> Result<Decimal<I, D>, StatsError> {
+        let count = U512::from(self.checked_count()?);
+        let (_, scaling) = sign_magnitude(I::SCALING_FACTOR);
+
+        // Var = (n * Σx² - (Σx)²) / n², which carries twice the scale.
+        let divisor = count * count * U512::from(scaling);
+
+        narrow(false, round_div_uint(self.dispersion()?, divisor, false, mode))
+    }
+
+    /// The population standard deviation, i.e. the square root of
+    /// [`variance`](Self::variance) computed exactly & rounded once according
+    /// to `mode`.
+    pub fn stddev(&self, mode: RoundingMode) -> Result<Decimal<I, D>, StatsError> {
+        let count = U1024::from(self.checked_count()?);
+
+        // σ = sqrt(dispersion) / n, at the decimal's scale.
+        let radicand = U1024::from(self.dispersion()?);
+        let divisor = count * count;
+        let floor = (radicand / divisor).root(2);
+        if floor * floor * divisor == radicand {
+            return narrow(false, floor);
+        }
+
+        // Compare to the midpoint scaled by `4 * n²`. The root can sit exactly
+        // on the midpoint for an even count (e.g. raw values `[0, 1]` give
+        // `0.5`), which `rounds_away` resolves like any other tie.
+        let midpoint = (floor * U1024::from(2) + U1024::ONE).pow(U1024::from(2)) * divisor;
+        let half = (radicand << 2usize).cmp(&midpoint);
+        let root = match mode.rounds_away(false, floor.bit(0), half) {
+            true => floor + U1024::ONE,
+            false => floor,
+        };
+
+        narrow(false, root)
+    }
+
+    const fn checked_count(&self) -> Result<u64, StatsError> {
+        match (self.overflowed, self.count) {
+            (true, _) => Err(StatsError::Overflow),
+            (false, 0) => Err(StatsError::Empty),
+            (false, count) => Ok(count),
+        }
+    }
+
+    /// Computes `n * Σx² - (Σx)²`, which is never negative.
+    fn dispersion(&self) -> Result<U512, StatsError> {
+        let (_, sum) = self.sum.sign_magnitude();
+
+        U512::from(self.count)
+            .checked_mul(self.sum_squares)
+            .zip(sum.checked_mul(sum))
+            .map(|(lhs, rhs)| lhs - rhs)
+            .ok_or(StatsError::Overflow)
+    }
+}
+
+impl<I, const D: u8> Default for RunningStats<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<I, const D: u8> Extend<Decimal<I, D>> for RunningStats<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    fn extend<It: IntoIterator<Item = Decimal<I, D>>>(&mut self, iter: It) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<'a, I, const D: u8> Extend<&'a Decimal<I, D>> for RunningStats<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    fn extend<It: IntoIterator<Item = &'a Decimal<I, D>>>(&mut self, iter: It) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+fn running<I, const D: u8>(values: &[Decimal<I, D>]) -> RunningStats<I, D>
+where
+    I: ScaledInteger<D>,
+{
+    let mut stats = RunningStats::new();
+    stats.extend(values);
+
+    stats
+}
+
+/// Narrows a sign & magnitude pair back to the backing integer.
+fn narrow<I, const D: u8, const BITS: usize, const LIMBS: usize>(
+    negative: bool,
+    magnitude: ruint::Uint<BITS, LIMBS>,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    u128::try_from(magnitude)
+        .ok()
+        .and_then(|magnitude| from_sign_magnitude(negative, magnitude))
+        .map(Decimal)
+        .ok_or(StatsError::Overflow)
+}
+
+/// The arithmetic mean of `values`, rounded according to `mode`.
+pub fn mean<I, const D: u8>(
+    values: &[Decimal<I, D>],
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    running(values).mean(mode)
+}
+
+/// The mean of `values` weighted by `weights` (e.g. a VWAP), rounded according
+/// to `mode`.
+///
+/// Returns an error if the slices are empty or differ in length, or if the
+/// weights sum to zero.
+pub fn weighted_mean<I, const D: u8>(
+    values: &[Decimal<I, D>],
+    weights: &[Decimal<I, D>],
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    if values.len() != weights.len() {
+        return Err(StatsError::LengthMismatch);
+    }
+    if values.is_empty() {
+        return Err(StatsError::Empty);
+    }
+
+    let mut numer = SignedSum::ZERO;
+    let mut denom = SignedSum::ZERO;
+    for (value, weight) in values.iter().zip(weights) {
+        let (value_negative, value) = sign_magnitude(value.0);
+        let (weight_negative, weight) = sign_magnitude(weight.0);
+
+        // Σ(v * w) carries twice the scale, so dividing by Σw leaves the
+        // decimal's scale.
+        numer = numer
+            .checked_add(value_negative != weight_negative, U512::from(value) * U512::from(weight))
+            .ok_or(StatsError::Overflow)?;
+        denom = denom
+            .checked_add(weight_negative, U512::from(weight))
+            .ok_or(StatsError::Overflow)?;
+    }
+
+    let (numer_negative, numer) = numer.sign_magnitude();
+    let (denom_negative, denom) = denom.sign_magnitude();
+    if denom.is_zero() {
+        return Err(StatsError::ZeroWeight);
+    }
+
+    let negative = !numer.is_zero() && numer_negative != denom_negative;
+    narrow(negative, round_div_uint(numer, denom, negative, mode))
+}
+
+/// The population variance of `values`, rounded according to `mode`.
+pub fn variance<I, const D: u8>(
+    values: &[Decimal<I, D>],
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    running(values).variance(mode)
+}
+
+/// The population standard deviation of `values`, rounded once according to
+/// `mode`.
+pub fn stddev<I, const D: u8>(
+    values: &[Decimal<I, D>],
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    running(values).stddev(mode)
+}
+
+/// The smallest of `values`.
+pub fn min<I, const D: u8>(values: &[Decimal<I, D>]) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    values.iter().min().copied().ok_or(StatsError::Empty)
+}
+
+/// The largest of `values`.
+pub fn max<I, const D: u8>(values: &[Decimal<I, D>]) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    values.iter().max().copied().ok_or(StatsError::Empty)
+}
+
+/// The median of `values`, averaging the two middle values (rounded according
+/// to `mode`) when the count is even.
+pub fn median<I, const D: u8>(
+    values: &[Decimal<I, D>],
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    quantile(values, U256::ONE, U256::from(2), Interpolation::Linear, mode)
+}
+
+/// The `p`-th percentile of `values`, where `p` is within `[0, 100]` and may
+/// use any backing & scale.
+///
+/// The rank `p / 100 * (n - 1)` of the sorted values is resolved according to
+/// `interpolation`; only [`Interpolation::Linear`] and
+/// [`Interpolation::Midpoint`] round (according to `mode`).
+pub fn percentile<I, const D: u8, P, const DP: u8>(
+    values: &[Decimal<I, D>],
+    p: Decimal<P, DP>,
+    interpolation: Interpolation,
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+    P: ScaledInteger<DP>,
+{
+    let (negative, p) = sign_magnitude(p.0);
+    let (_, scaling) = sign_magnitude(P::SCALING_FACTOR);
+    let denom = U256::from(scaling) * U256::from(100);
+    if negative || U256::from(p) > denom {
+        return Err(StatsError::InvalidPercentile);
+    }
+
+    quantile(values, U256::from(p), denom, interpolation, mode)
+}
+
+/// Resolves the rank `numer / denom * (n - 1)` of the sorted `values`.
+fn quantile<I, const D: u8>(
+    values: &[Decimal<I, D>],
+    numer: U256,
+    denom: U256,
+    interpolation: Interpolation,
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    if values.is_empty() {
+        return Err(StatsError::Empty);
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    // SAFETY: `values` is non-empty.
+    #[allow(clippy::arithmetic_side_effects)]
+    let last = U256::from(sorted.len() - 1);
+    let (lower, remainder) = (last * numer).div_rem(denom);
+    let lower = lower.to::<usize>();
+    let upper = lower + usize::from(!remainder.is_zero());
+    let (low, high) = (sorted[lower], sorted[upper]);
+
+    match interpolation {
+        Interpolation::Lower => Ok(low),
+        Interpolation::Higher => Ok(high),
+        Interpolation::Nearest => match (remainder << 1usize).cmp(&denom) {
+            Ordering::Less => Ok(low),
+            Ordering::Greater => Ok(high),
+            Ordering::Equal => match lower % 2 {
+                0 => Ok(low),
+                _ => Ok(high),
+            },
+        },
+        Interpolation::Midpoint if remainder.is_zero() => Ok(low),
+        Interpolation::Midpoint => interpolate(low, high, U256::ONE, U256::from(2), mode),
+        Interpolation::Linear => interpolate(low, high, remainder, denom, mode),
+    }
+}
+
+/// Computes `low + (high - low) * numer / denom`, rounded according to `mode`.
+fn interpolate<I, const D: u8>(
+    low: Decimal<I, D>,
+    high: Decimal<I, D>,
+    numer: U256,
+    denom: U256,
+    mode: RoundingMode,
+) -> Result<Decimal<I, D>, StatsError>
+where
+    I: ScaledInteger<D>,
+{
+    let (low_negative, low) = sign_magnitude(low.0);
+    let (high_negative, high) = sign_magnitude(high.0);
+
+    // The result lies between `low` & `high` so none of this can overflow.
+    let (negative, magnitude) = SignedSum::ZERO
+        .checked_add(low_negative, U512::from(low) * U512::from(denom - numer))
+        .and_then(|sum| sum.checked_add(high_negative, U512::from(high) * U512::from(numer)))
+        .ok_or(StatsError::Overflow)?
+        .sign_magnitude();
+
+    narrow(negative, round_div_uint(magnitude, U512::from(denom), negative, mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use malachite::base::num::arithmetic::traits::Pow;
+    use malachite::{Integer, Rational};
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::macros::generate_tests_for_common_variants;
+    use crate::rounding::reference_round;
+
+    fn parse<const N: usize>(values: [&str; N]) -> Vec<Decimal<i64, 2>> {
+        values.iter().map(|x| x.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn errors() {
+        let empty: &[Decimal<i64, 2>] = &[];
+        let values = parse(["1", "-1"]);
+
+        assert_eq!(mean(empty, RoundingMode::HalfEven), Err(StatsError::Empty));
+        assert_eq!(min(empty), Err(StatsError::Empty));
+        assert_eq!(median(empty, RoundingMode::HalfEven), Err(StatsError::Empty));
+        assert_eq!(
+            weighted_mean(&values, &values[..1], RoundingMode::HalfEven),
+            Err(StatsError::LengthMismatch),
+        );
+        assert_eq!(
+            weighted_mean(&values, &parse(["1", "-1"]), RoundingMode::HalfEven),
+            Err(StatsError::ZeroWeight),
+        );
+        assert_eq!(
+            percentile(
+                &values,
+                Decimal::<i8, 0>(-1),
+                Interpolation::Linear,
+                RoundingMode::HalfEven
+            ),
+            Err(StatsError::InvalidPercentile),
+        );
+        assert_eq!(
+            percentile(
+                &values,
+                Decimal::<u16, 1>(1001),
+                Interpolation::Linear,
+                RoundingMode::HalfEven
+            ),
+            Err(StatsError::InvalidPercentile),
+        );
+        assert_eq!(
+            variance(&[Decimal::<i8, 1>::MIN, Decimal::MAX], RoundingMode::HalfEven),
+            Err(StatsError::Overflow),
+        );
+    }
+
+    #[test]
+    fn percentile_interpolations() {
+        // Matches `numpy.percentile([1, 2, 3, 4], 40, method=...)`.
+        let values = parse(["4", "1", "3", "2"]);
+        let p = Decimal::<u8, 0>(40);
+        let out = |interpolation| percentile(&values, p, interpolation, RoundingMode::HalfEven);
+
+        assert_eq!(out(Interpolation::Linear), Ok("2.20".parse().unwrap()));
+        assert_eq!(out(Interpolation::Lower), Ok("2".parse().unwrap()));
+        assert_eq!(out(Interpolation::Higher), Ok("3".parse().unwrap()));
+        assert_eq!(out(Interpolation::Nearest), Ok("2".parse().unwrap()));
+        assert_eq!(out(Interpolation::Midpoint), Ok("2.50".parse().unwrap()));
+        assert_eq!(median(&values, RoundingMode::HalfEven), Ok("2.50".parse().unwrap()));
+        assert_eq!(median(&values[..3], RoundingMode::HalfEven), Ok("3".parse().unwrap()));
+    }
+
+    #[test]
+    fn running_stats_merge() {
+        let values = parse(["-1.25", "3", "7.5", "-0.01"]);
+        let mut lhs = RunningStats::new();
+        let mut rhs = RunningStats::new();
+        lhs.extend(&values[..1]);
+        rhs.extend(&values[1..]);
+        lhs.merge(rhs);
+
+        assert_eq!(lhs, running(&values));
+        assert_eq!(lhs.count(), 4);
+        assert_eq!(lhs.min(), Ok(values[0]));
+        assert_eq!(lhs.max(), Ok(values[2]));
+        assert_eq!(RunningStats::<i64, 2>::default().max(), Err(StatsError::Empty));
+    }
+
+    #[test]
+    fn stddev_ties() {
+        // The exact standard deviation is 0.005, halfway between 0.00 & 0.01.
+        let values = [Decimal::<i64, 2>(0), Decimal(1)];
+
+        assert_eq!(stddev(&values, RoundingMode::HalfDown), Ok(Decimal(0)));
+        assert_eq!(stddev(&values, RoundingMode::HalfEven), Ok(Decimal(0)));
+        assert_eq!(stddev(&values, RoundingMode::HalfUp), Ok(Decimal(1)));
+    }
+
+    fn differential_fuzz_stats<I, const D: u8>()
+    where
+        I: ScaledInteger<D> + Arbitrary + Into<Integer> + 'static,
+        Rational: From<Decimal<I, D>>,
+    {
+        let scaling = <Rational as From<Integer>>::from(I::SCALING_FACTOR.into());
+        let expected = move |exact: &Rational, mode| {
+            let reference = reference_round(&(exact * &scaling), mode);
+            let fits = I::min_value().into() <= reference && reference <= I::max_value().into();
+
+            fits.then_some(reference)
+        };
+        let values = proptest::collection::vec(any::<(Decimal<I, D>, Decimal<I, D>)>(), 1..16);
+
+        proptest!(|(pairs in values, mode: RoundingMode, p in 0u8..=100)| {
+            let (values, weights): (Vec<_>, Vec<_>) = pairs.into_iter().unzip();
+            let exact: Vec<_> = values.iter().map(|x| Rational::from(*x)).collect();
+            let count = Rational::from_unsigneds(exact.len(), 1);
+            let out = |x: Result<Decimal<I, D>, StatsError>| x.ok().map(|x| x.0.into());
+
+            let mean_exact = exact.iter().sum::<Rational>() / &count;
+            assert_eq!(out(mean(&values, mode)), expected(&mean_exact, mode));
+
+            let variance_exact =
+                exact.iter().map(|x| (x - &mean_exact).pow(2u64)).sum::<Rational>() / &count;
+            assert_eq!(out(variance(&values, mode)), expected(&variance_exact, mode));
+
+            // The correctly rounded root brackets the exact variance.
+            let squared = |x: Integer| <Rational as From<Integer>>::from(x).pow(2u64);
+            let radicand = variance_exact
+                * <Rational as From<Integer>>::from(I::SCALING_FACTOR.into()).pow(2u64);
+            if let Some(floor) = out(stddev(&values, RoundingMode::Floor)) {
+                assert!(squared(floor.clone()) <= radicand && radicand < squared(floor + Integer::from(1)));
+            }
+            if let Some(ceiling) = out(stddev(&values, RoundingMode::Ceiling)) {
+                assert!(ceiling == 0 || squared(ceiling.clone() - Integer::from(1)) < radicand);
+                assert!(radicand <= squared(ceiling));
+            }
+
+            let weight_sum = weights.iter().map(|w| Rational::from(*w)).sum::<Rational>();
+            let weighted = exact.iter().zip(&weights).map(|(x, w)| x * Rational::from(*w)).sum::<Rational>();
+            match weighted_mean(&values, &weights, mode) {
+                Err(StatsError::ZeroWeight) => assert_eq!(weight_sum, 0),
+                out => assert_eq!(out.ok().map(|x| x.0.into()), expected(&(weighted / weight_sum), mode)),
+            }
+
+            let mut sorted = exact.clone();
+            sorted.sort();
+            let rank = Rational::from_unsigneds(usize::from(p) * (sorted.len() - 1), 100);
+            let lower = usize::try_from(&reference_round(&rank, RoundingMode::Floor)).unwrap();
+            let upper = usize::try_from(&reference_round(&rank, RoundingMode::Ceiling)).unwrap();
+            let fraction = &rank - Rational::from_unsigneds(lower, 1);
+            let linear = &sorted[lower] + (&sorted[upper] - &sorted[lower]) * fraction;
+            let p = Decimal::<u8, 0>(p);
+            assert_eq!(
+                out(percentile(&values, p, Interpolation::Linear, mode)),
+                expected(&linear, mode),
+            );
+            assert_eq!(
+                out(percentile(&values, p, Interpolation::Lower, mode)),
+                expected(&sorted[lower], mode),
+            );
+            assert_eq!(
+                out(percentile(&values, p, Interpolation::Higher, mode)),
+                expected(&sorted[upper], mode),
+            );
+            assert_eq!(out(min(&values)), expected(&sorted[0], mode));
+            assert_eq!(out(max(&values)), expected(&sorted[sorted.len() - 1], mode));
+        });
+    }
+
+    generate_tests_for_common_variants!(differential_fuzz_stats);
+}